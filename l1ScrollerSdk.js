@@ -1,19 +1,6 @@
-const { ethers } = require("ethers");
-
-// Define the ABI for the L1Scroller contract
-const abi = [
-    "function readUint(address l1_contract, uint256 slot) external view returns (uint256)",
-    "function readUint160(address l1_contract, uint256 slot) external view returns (uint160)",
-    "function readAddress(address l1_contract, uint256 slot) external view returns (address)",
-    "function readUint96(address l1_contract, uint256 slot) external view returns (uint96)",
-    "function readUint48(address l1_contract, uint256 slot) external view returns (uint48)",
-    "function readUint24(address l1_contract, uint256 slot) external view returns (uint24)",
-    "function readUint8(address l1_contract, uint256 slot) external view returns (uint8)",
-    "function readUint32(address l1_contract, uint256 slot) external view returns (uint32)",
-    "function readUint64(address l1_contract, uint256 slot) external view returns (uint64)",
-    "function readUint128(address l1_contract, uint256 slot) external view returns (uint128)",
-    "function readString(address l1_contract, uint256 slot) external view returns (string)"
-];
+// Thin wrapper over the scroller-rs Node bindings, which `npm install` builds via the
+// `prepare` script.
+const { Scroller } = require("scroller-node");
 
 // Define the contract address
 const CONTRACT_ADDRESS = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC";
@@ -21,71 +8,31 @@ const CONTRACT_ADDRESS = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC";
 /**
  * Initializes the L1Scroller SDK with a given provider.
  * @param {string} rpcUrl - The RPC URL to connect to.
+ * @param {string} [scrollerAddress] - L1Scroller deployment to read through.
  * @returns {Object} - SDK object with functions to call the contract.
  */
-function createL1ScrollerSdk(rpcUrl) {
-    const provider = new ethers.JsonRpcProvider(rpcUrl);
-    const contract = new ethers.Contract(CONTRACT_ADDRESS, abi, provider);
-
-    // Define SDK functions
-
-    async function readUint(l1Contract, slot) {
-        return await contract.readUint(l1Contract, slot);
-    }
-
-    async function readUint160(l1Contract, slot) {
-        return await contract.readUint160(l1Contract, slot);
-    }
-
-    async function readAddress(l1Contract, slot) {
-        return await contract.readAddress(l1Contract, slot);
-    }
-
-    async function readUint96(l1Contract, slot) {
-        return await contract.readUint96(l1Contract, slot);
-    }
-
-    async function readUint48(l1Contract, slot) {
-        return await contract.readUint48(l1Contract, slot);
-    }
-
-    async function readUint24(l1Contract, slot) {
-        return await contract.readUint24(l1Contract, slot);
-    }
-
-    async function readUint8(l1Contract, slot) {
-        return await contract.readUint8(l1Contract, slot);
-    }
-
-    async function readUint32(l1Contract, slot) {
-        return await contract.readUint32(l1Contract, slot);
-    }
-
-    async function readUint64(l1Contract, slot) {
-        return await contract.readUint64(l1Contract, slot);
-    }
-
-    async function readUint128(l1Contract, slot) {
-        return await contract.readUint128(l1Contract, slot);
-    }
+function createL1ScrollerSdk(rpcUrl, scrollerAddress = CONTRACT_ADDRESS) {
+    const scroller = new Scroller(rpcUrl, scrollerAddress);
 
-    async function readString(l1Contract, slot) {
-        return await contract.readString(l1Contract, slot);
-    }
+    // Slots may be given as numbers, strings or BigInts; integer results are BigInts.
+    const reader = (method) => (l1Contract, slot) => scroller[method](l1Contract, BigInt(slot));
 
-    // Export all functions as part of the SDK
     return {
-        readUint,
-        readUint160,
-        readAddress,
-        readUint96,
-        readUint48,
-        readUint24,
-        readUint8,
-        readUint32,
-        readUint64,
-        readUint128,
-        readString
+        scroller,
+        readSlot: reader("readSlot"),
+        readMultipleSlots: (l1Contract, slots) =>
+            scroller.readMultipleSlots(l1Contract, slots.map(BigInt)),
+        readUint: reader("readUint"),
+        readUint160: reader("readUint160"),
+        readAddress: reader("readAddress"),
+        readUint96: reader("readUint96"),
+        readUint48: reader("readUint48"),
+        readUint24: reader("readUint24"),
+        readUint8: reader("readUint8"),
+        readUint32: reader("readUint32"),
+        readUint64: reader("readUint64"),
+        readUint128: reader("readUint128"),
+        readString: reader("readString")
     };
 }
 
@@ -1,3 +1,5 @@
+import warnings
+
 from web3 import Web3
 
 # ABI for the L1Scroller contract
@@ -49,6 +51,11 @@ CONTRACT_ADDRESS = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC"
 
 class L1ScrollerSdk:
     def __init__(self, rpc_url):
+        warnings.warn(
+            "L1ScrollerSdk is deprecated, use scroller.Scroller from scroller-rs/scroller-py",
+            DeprecationWarning,
+            stacklevel=2,
+        )
         self.web3 = Web3(Web3.HTTPProvider(rpc_url))
         if not self.web3.is_connected():
             raise ConnectionError("Failed to connect to the RPC URL.")
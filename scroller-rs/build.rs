@@ -0,0 +1,64 @@
+//! Generates `src/bindings` from the contracts' forge artifacts.
+//!
+//! The artifacts are taken from the forge `out/` directory of the repository (or
+//! `SCROLLER_FORGE_OUT`) when `forge build` has been run, and from the copies checked in
+//! under `abi/` otherwise. Cargo only notices an `out/` directory created after the last
+//! build once `build.rs` or `abi/` changes, e.g. after `cargo clean -p scroller-rs`.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Binding module, contract name and Solidity file of each contract.
+const CONTRACTS: [(&str, &str, &str); 3] = [
+    ("l1scroller", "L1Scroller", "L1Scroller.sol"),
+    ("erc20l1scroller", "ERC20L1Scroller", "ERC20L1Scroller.sol"),
+    ("nftl1scroller", "NFTL1Scroller", "NFTL1Scroller.sol"),
+];
+
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let forge_out = env::var_os("SCROLLER_FORGE_OUT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| crate_dir.join("../out"));
+
+    for (module, contract, file) in CONTRACTS {
+        let checked_in = crate_dir.join("abi").join(format!("{contract}.json"));
+        let forge = forge_out.join(file).join(format!("{contract}.json"));
+        let artifact = if forge.exists() {
+            if abi(&forge) != abi(&checked_in) {
+                println!(
+                    "cargo:warning={} differs from {}, copy it over to update the checked-in ABI",
+                    forge.display(),
+                    checked_in.display()
+                );
+            }
+            forge
+        } else {
+            checked_in
+        };
+        println!("cargo:rerun-if-changed={}", artifact.display());
+
+        let bindings = format!(
+            "alloy::sol!(\n    #[sol(rpc)]\n    {contract},\n    {:?}\n);\n",
+            artifact.canonicalize().unwrap()
+        );
+        fs::write(out_dir.join(format!("{module}.rs")), bindings).unwrap();
+    }
+
+    // Watching a missing path would rerun this script on every build.
+    if forge_out.exists() {
+        println!("cargo:rerun-if-changed={}", forge_out.display());
+    }
+    println!("cargo:rerun-if-changed=abi");
+    println!("cargo:rerun-if-env-changed=SCROLLER_FORGE_OUT");
+}
+
+fn abi(artifact: &Path) -> serde_json::Value {
+    let json = fs::read_to_string(artifact)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", artifact.display()));
+    let mut artifact: serde_json::Value = serde_json::from_str(&json).unwrap();
+    artifact["abi"].take()
+}
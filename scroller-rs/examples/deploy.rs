@@ -0,0 +1,28 @@
+//! Deploys the L1Scroller contract suite and records it in a deployments file.
+//!
+//! ```bash
+//! RPC_URL=https://l1sload-rpc.scroll.io PRIVATE_KEY=<KEY> cargo run --example deploy -- deployments.json
+//! ```
+use std::env;
+
+use alloy::signers::local::PrivateKeySigner;
+use scroller_rs::deploy::{deploy_suite, DeployConfig};
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let rpc_url = env::var("RPC_URL")?;
+    let signer: PrivateKeySigner = env::var("PRIVATE_KEY")?.parse()?;
+    let out = env::args()
+        .nth(1)
+        .unwrap_or_else(|| "deployments.json".to_string());
+
+    let suite = deploy_suite(&rpc_url, signer, &DeployConfig::default()).await?;
+    println!("chain id:        {}", suite.chain_id);
+    println!("L1Scroller:      {}", suite.l1_scroller.address);
+    println!("ERC20L1Scroller: {}", suite.erc20_l1scroller.address);
+    println!("NFTL1Scroller:   {}", suite.nft_l1scroller.address);
+
+    suite.record_to_file(&out)?;
+    println!("recorded in {out}");
+    Ok(())
+}
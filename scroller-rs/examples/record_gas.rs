@@ -0,0 +1,102 @@
+//! Records `eth_estimateGas` of L1Scroller calls into
+//! `tests/fixtures/eth_estimate_gas.json`, which `gas_plan` is tested against.
+//!
+//! Estimates the calls of `tests/fixtures/revm_gas_used.json` on a node running the
+//! L1SLOAD precompile, e.g. the devnet or an anvil fork of it, through the L1Scroller at
+//! `L1_SCROLLER`. `readString` is estimated once, for the string the L1 contract stores.
+//!
+//! ```bash
+//! RPC_URL=https://l1sload-rpc.scroll.io L1_SCROLLER=0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC \
+//!     cargo run --example record_gas
+//! ```
+use std::{env, fs, str::FromStr};
+
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    sol_types::SolCall,
+    transports::http::reqwest::Url,
+};
+use scroller_rs::bindings::l1scroller::L1Scroller;
+use serde_json::{json, Value};
+
+const CASES: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/revm_gas_used.json"
+);
+const OUT: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/eth_estimate_gas.json"
+);
+
+fn calldata(method: &str, l1_contract: Address, slots: &[U256]) -> eyre::Result<Vec<u8>> {
+    let slot = slots[0];
+    Ok(match method {
+        "readSlot" => L1Scroller::readSlotCall { l1_contract, slot }.abi_encode(),
+        "readMultipleSlots" => L1Scroller::readMultipleSlotsCall {
+            l1_contract,
+            slot: slots.to_vec(),
+        }
+        .abi_encode(),
+        "readString" => L1Scroller::readStringCall { l1_contract, slot }.abi_encode(),
+        "readUint" => L1Scroller::readUintCall { l1_contract, slot }.abi_encode(),
+        "readUint160" => L1Scroller::readUint160Call { l1_contract, slot }.abi_encode(),
+        "readAddress" => L1Scroller::readAddressCall { l1_contract, slot }.abi_encode(),
+        "readUint96" => L1Scroller::readUint96Call { l1_contract, slot }.abi_encode(),
+        "readUint48" => L1Scroller::readUint48Call { l1_contract, slot }.abi_encode(),
+        "readUint24" => L1Scroller::readUint24Call { l1_contract, slot }.abi_encode(),
+        "readUint8" => L1Scroller::readUint8Call { l1_contract, slot }.abi_encode(),
+        "readUint32" => L1Scroller::readUint32Call { l1_contract, slot }.abi_encode(),
+        "readUint64" => L1Scroller::readUint64Call { l1_contract, slot }.abi_encode(),
+        "readUint128" => L1Scroller::readUint128Call { l1_contract, slot }.abi_encode(),
+        method => eyre::bail!("unknown method {method}"),
+    })
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    let provider = ProviderBuilder::new().on_http(Url::from_str(&env::var("RPC_URL")?)?);
+    let scroller: Address = env::var("L1_SCROLLER")?.parse()?;
+    let cases: Value = serde_json::from_str(&fs::read_to_string(CASES)?)?;
+    let l1_contract: Address = serde_json::from_value(cases["l1Contract"].clone())?;
+
+    let chain_id = provider.get_chain_id().await?;
+    let block = provider.get_block_number().await?;
+    let mut estimates = Vec::new();
+    let mut string_recorded = false;
+    for case in cases["estimates"].as_array().into_iter().flatten() {
+        let method = case["method"].as_str().unwrap_or_default();
+        let slots: Vec<U256> = serde_json::from_value(case["slots"].clone())?;
+        let tx = TransactionRequest::default()
+            .with_to(scroller)
+            .with_input(calldata(method, l1_contract, &slots)?);
+        let mut estimate = json!({ "method": method, "slots": case["slots"] });
+        if method == "readString" {
+            if string_recorded {
+                continue;
+            }
+            string_recorded = true;
+            let output = provider.call(&tx).block(block.into()).await?;
+            let string = L1Scroller::readStringCall::abi_decode_returns(&output, true)?._0;
+            estimate["stringLength"] = json!(string.trim_end_matches('\0').len());
+        }
+        let gas = provider.estimate_gas(&tx).block(block.into()).await?;
+        println!("{method} {slots:?}: {gas}");
+        estimate["gas"] = json!(gas);
+        estimates.push(estimate);
+    }
+
+    let fixture = json!({
+        "source": format!(
+            "eth_estimateGas on chain {chain_id} at L2 block {block}, through the L1Scroller \
+             at {scroller}, recorded with `cargo run --example record_gas`"
+        ),
+        "l1Contract": l1_contract,
+        "estimates": estimates,
+    });
+    fs::write(OUT, serde_json::to_string_pretty(&fixture)? + "\n")?;
+    println!("recorded in {OUT}");
+    Ok(())
+}
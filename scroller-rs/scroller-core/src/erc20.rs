@@ -0,0 +1,51 @@
+//! Storage slots of OpenZeppelin `ERC20`, mirroring the `ERC20L1Scroller` library.
+
+use alloy_primitives::{Address, U256};
+
+use crate::storage::{address_mapping_slot, mapping_slot};
+
+pub const BALANCES_SLOT: U256 = U256::from_limbs([0, 0, 0, 0]);
+pub const ALLOWANCES_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+pub const TOTAL_SUPPLY_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+pub const NAME_SLOT: U256 = U256::from_limbs([3, 0, 0, 0]);
+pub const SYMBOL_SLOT: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+/// Slot of `_balances[account]`.
+pub fn balance_slot(account: Address) -> U256 {
+    address_mapping_slot(account, BALANCES_SLOT)
+}
+
+/// Slot of `_allowances[owner][spender]`.
+///
+/// Unlike `ERC20L1Scroller.allowances_slot`, which packs the addresses without padding,
+/// this follows the solc layout for nested mappings.
+pub fn allowance_slot(owner: Address, spender: Address) -> U256 {
+    mapping_slot(
+        spender.into_word(),
+        address_mapping_slot(owner, ALLOWANCES_SLOT),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, keccak256};
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+
+    #[test]
+    fn test_slots_match_solidity() {
+        let owner = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+        let spender = address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+        // abi.encodePacked(uint256(uint160(account)), BALANCES_OPENZEPPELIN)
+        let packed = (U256::from_be_slice(owner.as_slice()), BALANCES_SLOT).abi_encode_packed();
+        assert_eq!(
+            balance_slot(owner),
+            U256::from_be_bytes(keccak256(packed).0)
+        );
+
+        let inner = keccak256((owner, ALLOWANCES_SLOT).abi_encode());
+        let outer = keccak256((spender, inner).abi_encode());
+        assert_eq!(allowance_slot(owner, spender), U256::from_be_bytes(outer.0));
+    }
+}
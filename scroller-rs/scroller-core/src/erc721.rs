@@ -0,0 +1,50 @@
+//! Storage slots of OpenZeppelin `ERC721`, mirroring the `NFTL1Scroller` library.
+
+use alloy_primitives::{Address, U256};
+
+use crate::storage::{address_mapping_slot, uint_mapping_slot};
+
+pub const NAME_SLOT: U256 = U256::from_limbs([0, 0, 0, 0]);
+pub const SYMBOL_SLOT: U256 = U256::from_limbs([1, 0, 0, 0]);
+pub const OWNERS_SLOT: U256 = U256::from_limbs([2, 0, 0, 0]);
+pub const BALANCES_SLOT: U256 = U256::from_limbs([3, 0, 0, 0]);
+pub const TOKEN_APPROVALS_SLOT: U256 = U256::from_limbs([4, 0, 0, 0]);
+
+/// Slot of `_balances[owner]`.
+pub fn balance_slot(owner: Address) -> U256 {
+    address_mapping_slot(owner, BALANCES_SLOT)
+}
+
+/// Slot of `_owners[token_id]`.
+pub fn owner_slot(token_id: U256) -> U256 {
+    uint_mapping_slot(token_id, OWNERS_SLOT)
+}
+
+/// Slot of `_tokenApprovals[token_id]`.
+pub fn token_approval_slot(token_id: U256) -> U256 {
+    uint_mapping_slot(token_id, TOKEN_APPROVALS_SLOT)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::keccak256;
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+
+    #[test]
+    fn test_slots_match_solidity() {
+        let token_id = U256::from(42);
+        // abi.encodePacked(tokenId, ID_TO_OWNER_SLOT_OPENZEPPELIN)
+        let packed = (token_id, OWNERS_SLOT).abi_encode_packed();
+        assert_eq!(
+            owner_slot(token_id),
+            U256::from_be_bytes(keccak256(packed).0)
+        );
+        let packed = (token_id, TOKEN_APPROVALS_SLOT).abi_encode_packed();
+        assert_eq!(
+            token_approval_slot(token_id),
+            U256::from_be_bytes(keccak256(packed).0)
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! The parts of scroller-rs that need neither networking nor `std`: storage slot
+//! derivation (`storage`, `erc20`, `erc721`), decoding of storage words, and the L1SLOAD
+//! precompile byte format (`precompile`). Re-exported by scroller-rs.
+
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
+
+pub mod erc20;
+pub mod erc721;
+pub mod precompile;
+pub mod storage;
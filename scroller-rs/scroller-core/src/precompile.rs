@@ -0,0 +1,156 @@
+//! Byte format of the L1SLOAD precompile.
+//!
+//! The input is the 20-byte L1 contract address followed by one 32-byte big-endian
+//! word per storage slot, i.e. `abi.encodePacked(l1Contract, slots)` as built by
+//! `L1Scroller.sol`. The output is one 32-byte word per slot, in the same order.
+
+use alloc::vec::Vec;
+
+use alloy_primitives::{address, Address, Bytes, B256, U256};
+use thiserror::Error;
+
+/// Address of the L1SLOAD precompile.
+pub const L1SLOAD_ADDRESS: Address = address!("0000000000000000000000000000000000000101");
+
+/// Maximum number of slots the L1SLOAD precompile reads in one call.
+pub const L1SLOAD_MAX_SLOTS: usize = 5;
+
+/// Address of the L1Blocks predeploy, which holds the L1 block L1SLOAD reads from.
+pub const L1_BLOCKS_ADDRESS: Address = address!("5300000000000000000000000000000000000001");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PrecompileError {
+    #[error("L1SLOAD reads between 1 and {max} slots per call, got {requested}")]
+    InvalidSlotCount { requested: usize, max: usize },
+
+    #[error("L1SLOAD input must be 20 bytes followed by 32-byte slots, got {0} bytes")]
+    InputLength(usize),
+
+    #[error("L1SLOAD returned {got} bytes, expected {expected}")]
+    OutputLength { expected: usize, got: usize },
+}
+
+/// Checks that a single L1SLOAD call can read `slots` slots.
+pub fn check_slot_count(slots: usize) -> Result<(), PrecompileError> {
+    if slots == 0 || slots > L1SLOAD_MAX_SLOTS {
+        return Err(PrecompileError::InvalidSlotCount {
+            requested: slots,
+            max: L1SLOAD_MAX_SLOTS,
+        });
+    }
+    Ok(())
+}
+
+/// Encodes the precompile input for reading `slots` of `l1_contract`.
+pub fn encode_l1sload_input(
+    l1_contract: Address,
+    slots: &[U256],
+) -> Result<Bytes, PrecompileError> {
+    check_slot_count(slots.len())?;
+    let mut input = Vec::with_capacity(Address::len_bytes() + 32 * slots.len());
+    input.extend_from_slice(l1_contract.as_slice());
+    for slot in slots {
+        input.extend_from_slice(&slot.to_be_bytes::<32>());
+    }
+    Ok(input.into())
+}
+
+/// Decodes a precompile input back into the L1 contract and the slots it reads,
+/// e.g. from a trace.
+pub fn decode_l1sload_input(input: &[u8]) -> Result<(Address, Vec<U256>), PrecompileError> {
+    let Some(words) = input.len().checked_sub(Address::len_bytes()) else {
+        return Err(PrecompileError::InputLength(input.len()));
+    };
+    if words % 32 != 0 {
+        return Err(PrecompileError::InputLength(input.len()));
+    }
+    check_slot_count(words / 32)?;
+    let (l1_contract, slots) = input.split_at(Address::len_bytes());
+    Ok((
+        Address::from_slice(l1_contract),
+        slots.chunks_exact(32).map(U256::from_be_slice).collect(),
+    ))
+}
+
+/// Splits the precompile output for a read of `slots` slots into one word per slot.
+pub fn decode_l1sload_output(output: &Bytes, slots: usize) -> Result<Vec<B256>, PrecompileError> {
+    if output.len() != 32 * slots {
+        return Err(PrecompileError::OutputLength {
+            expected: 32 * slots,
+            got: output.len(),
+        });
+    }
+    Ok(output.chunks_exact(32).map(B256::from_slice).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_sol_types::SolValue;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_encode_matches_encode_packed() {
+        let l1_contract = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+        let slots = vec![U256::from(0), U256::from(1), U256::MAX];
+        let input = encode_l1sload_input(l1_contract, &slots).unwrap();
+        assert_eq!(input.len(), 20 + 3 * 32);
+        assert_eq!(input.to_vec(), (l1_contract, slots).abi_encode_packed());
+
+        assert_eq!(
+            encode_l1sload_input(l1_contract, &[U256::ZERO; L1SLOAD_MAX_SLOTS + 1]),
+            Err(PrecompileError::InvalidSlotCount {
+                requested: 6,
+                max: L1SLOAD_MAX_SLOTS
+            })
+        );
+        assert!(encode_l1sload_input(l1_contract, &[]).is_err());
+    }
+
+    #[test]
+    fn test_malformed_lengths() {
+        assert_eq!(
+            decode_l1sload_input(&[0u8; 19]),
+            Err(PrecompileError::InputLength(19))
+        );
+        assert_eq!(
+            decode_l1sload_input(&[0u8; 20 + 31]),
+            Err(PrecompileError::InputLength(51))
+        );
+        assert!(decode_l1sload_input(&[0u8; 20]).is_err());
+        assert_eq!(
+            decode_l1sload_output(&Bytes::from(vec![0u8; 40]), 1),
+            Err(PrecompileError::OutputLength {
+                expected: 32,
+                got: 40
+            })
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_input_round_trip(
+            l1_contract in any::<[u8; 20]>(),
+            slots in prop::collection::vec(any::<[u8; 32]>(), 1..=L1SLOAD_MAX_SLOTS),
+        ) {
+            let l1_contract = Address::from(l1_contract);
+            let slots: Vec<U256> = slots.iter().map(|s| U256::from_be_bytes(*s)).collect();
+            let input = encode_l1sload_input(l1_contract, &slots).unwrap();
+            prop_assert_eq!(decode_l1sload_input(&input).unwrap(), (l1_contract, slots));
+        }
+
+        #[test]
+        fn test_output_round_trip(words in prop::collection::vec(any::<[u8; 32]>(), 0..8)) {
+            let words: Vec<B256> = words.into_iter().map(B256::from).collect();
+            let output: Bytes = words.concat().into();
+            prop_assert_eq!(decode_l1sload_output(&output, words.len()).unwrap(), words);
+        }
+
+        #[test]
+        fn test_decode_never_panics(bytes in prop::collection::vec(any::<u8>(), 0..256), n in 0usize..8) {
+            let _ = decode_l1sload_input(&bytes);
+            let _ = decode_l1sload_output(&Bytes::from(bytes), n);
+        }
+    }
+}
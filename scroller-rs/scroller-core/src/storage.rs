@@ -0,0 +1,343 @@
+use alloc::{format, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use alloy_primitives::{keccak256, Address, Bytes, B256, I256, U256};
+use thiserror::Error;
+
+/// Longest `string` or `bytes` value read from storage, 1 MiB.
+pub const MAX_STRING_LEN: usize = 1 << 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum StorageError {
+    /// The word is not the length of a long `string`/`bytes`, e.g. because it holds an odd
+    /// uint, or the value is longer than [`MAX_STRING_LEN`].
+    #[error("invalid string length {0}, expected 32 to {MAX_STRING_LEN} bytes")]
+    StringLength(U256),
+    #[error("{size}-byte value at offset {offset} does not fit in a 32-byte slot")]
+    Offset { offset: usize, size: usize },
+}
+
+/// Slot of `mapping[key]` for a mapping declared at `base`, i.e. `keccak256(key . base)`
+/// with the key left-padded to 32 bytes.
+pub fn mapping_slot(key: B256, base: U256) -> U256 {
+    let mut preimage = [0u8; 64];
+    preimage[..32].copy_from_slice(key.as_slice());
+    preimage[32..].copy_from_slice(&base.to_be_bytes::<32>());
+    keccak256(preimage).into()
+}
+
+/// [`mapping_slot`] for an `address` key.
+pub fn address_mapping_slot(key: Address, base: U256) -> U256 {
+    mapping_slot(key.into_word(), base)
+}
+
+/// [`mapping_slot`] for a `uint256` key.
+pub fn uint_mapping_slot(key: U256, base: U256) -> U256 {
+    mapping_slot(key.into(), base)
+}
+
+/// First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+pub fn data_slot(slot: U256) -> U256 {
+    keccak256(slot.to_be_bytes::<32>()).into()
+}
+
+/// How a `string` or `bytes` value is stored, as read from its declaration slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringSlot {
+    /// Up to 31 bytes stored in the slot itself.
+    Short(Bytes),
+    /// `len` bytes stored from [`data_slot`] onwards.
+    Long { len: usize },
+}
+
+impl StringSlot {
+    /// Fails for odd words that are not a long value's length, see [`StorageError`].
+    pub fn decode(word: B256) -> Result<Self, StorageError> {
+        let value = U256::from_be_bytes(word.0);
+        if value.bit(0) {
+            return Ok(StringSlot::Long {
+                len: check_len(value >> 1)?,
+            });
+        }
+        let len = (word[31] / 2).min(31) as usize;
+        Ok(StringSlot::Short(Bytes::copy_from_slice(&word[..len])))
+    }
+
+    /// Slots holding the data of a long value declared at `slot`.
+    pub fn data_slots(&self, slot: U256) -> Result<Vec<U256>, StorageError> {
+        match self {
+            StringSlot::Short(_) => Ok(Vec::new()),
+            StringSlot::Long { len } => {
+                let len = check_len(U256::from(*len))?;
+                let first = data_slot(slot);
+                Ok((0..len.div_ceil(32))
+                    .map(|i| first.wrapping_add(U256::from(i)))
+                    .collect())
+            }
+        }
+    }
+}
+
+/// Long values hold at least 32 bytes, shorter ones are stored in the slot itself.
+fn check_len(len: U256) -> Result<usize, StorageError> {
+    if len < U256::from(32) || len > U256::from(MAX_STRING_LEN) {
+        return Err(StorageError::StringLength(len));
+    }
+    Ok(len.to())
+}
+
+/// Solidity value types a storage word can be decoded as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    Uint(usize),
+    Int(usize),
+    Address,
+    Bool,
+    FixedBytes(usize),
+    String,
+}
+
+impl ValueType {
+    /// Bytes the value occupies in its slot; `string` takes the whole slot.
+    pub fn size(&self) -> usize {
+        match self {
+            ValueType::Uint(bits) | ValueType::Int(bits) => bits / 8,
+            ValueType::Address => 20,
+            ValueType::Bool => 1,
+            ValueType::FixedBytes(len) => *len,
+            ValueType::String => 32,
+        }
+    }
+}
+
+impl FromStr for ValueType {
+    type Err = String;
+
+    /// Parses Solidity type names (`uint96`, `int`, `address`, `bytes32`, ...) as well as
+    /// the `t_*` names used in forge storage layouts.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.strip_prefix("t_").unwrap_or(s);
+        let bits = |digits: &str, max: usize, step: usize| -> Result<usize, String> {
+            if digits.is_empty() {
+                return Ok(max);
+            }
+            match digits.parse::<usize>() {
+                Ok(n) if n > 0 && n <= max && n % step == 0 => Ok(n),
+                _ => Err(format!("unsupported type `{s}`")),
+            }
+        };
+        match name {
+            "address" | "address_payable" => Ok(ValueType::Address),
+            "bool" => Ok(ValueType::Bool),
+            "string" | "string_storage" => Ok(ValueType::String),
+            _ if name.starts_with("contract(") => Ok(ValueType::Address),
+            _ => {
+                if let Some(digits) = name.strip_prefix("uint") {
+                    Ok(ValueType::Uint(bits(digits, 256, 8)?))
+                } else if let Some(digits) = name.strip_prefix("int") {
+                    Ok(ValueType::Int(bits(digits, 256, 8)?))
+                } else if let Some(digits) = name.strip_prefix("bytes") {
+                    if digits.is_empty() {
+                        return Err(format!("unsupported type `{s}`"));
+                    }
+                    Ok(ValueType::FixedBytes(bits(digits, 32, 1)?))
+                } else if name.starts_with("enum(") {
+                    Ok(ValueType::Uint(8))
+                } else {
+                    Err(format!("unsupported type `{s}`"))
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueType::Uint(bits) => write!(f, "uint{bits}"),
+            ValueType::Int(bits) => write!(f, "int{bits}"),
+            ValueType::Address => write!(f, "address"),
+            ValueType::Bool => write!(f, "bool"),
+            ValueType::FixedBytes(len) => write!(f, "bytes{len}"),
+            ValueType::String => write!(f, "string"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DecodedValue {
+    Uint(U256),
+    Int(I256),
+    Address(Address),
+    Bool(bool),
+    Bytes(Bytes),
+    String(String),
+}
+
+impl fmt::Display for DecodedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodedValue::Uint(value) => write!(f, "{value}"),
+            DecodedValue::Int(value) => write!(f, "{value}"),
+            DecodedValue::Address(value) => write!(f, "{value}"),
+            DecodedValue::Bool(value) => write!(f, "{value}"),
+            DecodedValue::Bytes(value) => write!(f, "{value}"),
+            DecodedValue::String(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DecodedValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            DecodedValue::Bool(value) => serializer.serialize_bool(*value),
+            _ => serializer.collect_str(self),
+        }
+    }
+}
+
+/// Checks that a value of `size` bytes stored `offset` bytes from the right fits in a slot.
+pub fn check_offset(offset: usize, size: usize) -> Result<(), StorageError> {
+    match offset.checked_add(size) {
+        Some(end) if end <= 32 => Ok(()),
+        _ => Err(StorageError::Offset { offset, size }),
+    }
+}
+
+/// Extracts the `size` bytes stored `offset` bytes from the right of `word`, the way
+/// solc packs values sharing a slot.
+pub fn extract(word: B256, offset: usize, size: usize) -> Result<U256, StorageError> {
+    check_offset(offset, size)?;
+    let value = U256::from_be_bytes(word.0) >> (offset * 8);
+    if size == 32 {
+        return Ok(value);
+    }
+    Ok(value & ((U256::from(1) << (size * 8)) - U256::from(1)))
+}
+
+/// Decodes the value of type `ty` stored at `offset` in `word`.
+///
+/// `string` values are decoded from the slot itself, so long strings come back empty;
+/// use [`StringSlot`] to read their data slots.
+pub fn decode_word(word: B256, offset: usize, ty: ValueType) -> Result<DecodedValue, StorageError> {
+    let raw = extract(word, offset, ty.size())?;
+    Ok(match ty {
+        ValueType::Uint(_) => DecodedValue::Uint(raw),
+        ValueType::Int(bits) => {
+            let negative = bits < 256 && raw.bit(bits - 1);
+            let value = if negative {
+                raw | (U256::MAX << bits)
+            } else {
+                raw
+            };
+            DecodedValue::Int(I256::from_raw(value))
+        }
+        ValueType::Address => DecodedValue::Address(Address::from_word(raw.into())),
+        ValueType::Bool => DecodedValue::Bool(!raw.is_zero()),
+        ValueType::FixedBytes(len) => {
+            DecodedValue::Bytes(Bytes::copy_from_slice(&raw.to_be_bytes::<32>()[32 - len..]))
+        }
+        ValueType::String => match StringSlot::decode(word)? {
+            StringSlot::Short(bytes) => {
+                DecodedValue::String(String::from_utf8_lossy(&bytes).into_owned())
+            }
+            StringSlot::Long { .. } => DecodedValue::String(String::new()),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{address, b256};
+    use alloy_sol_types::SolValue;
+
+    use super::*;
+
+    #[test]
+    fn test_mapping_slot() {
+        let key = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+        let base = U256::from(3);
+        assert_eq!(
+            address_mapping_slot(key, base),
+            U256::from_be_bytes(keccak256((key, base).abi_encode()).0)
+        );
+        assert_eq!(
+            data_slot(U256::ZERO),
+            U256::from_be_bytes(
+                b256!("290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563").0
+            )
+        );
+    }
+
+    #[test]
+    fn test_decode_packed_and_strings() {
+        // uint96 0x0102 at offset 0, address 0x..ff at offset 12.
+        let mut word = [0u8; 32];
+        word[30] = 1;
+        word[31] = 2;
+        word[19] = 0xff;
+        let word = B256::from(word);
+        assert_eq!(
+            decode_word(word, 0, "uint96".parse().unwrap()),
+            Ok(DecodedValue::Uint(U256::from(0x0102)))
+        );
+        assert_eq!(
+            decode_word(word, 12, ValueType::Address),
+            Ok(DecodedValue::Address(address!(
+                "00000000000000000000000000000000000000ff"
+            )))
+        );
+        assert_eq!(
+            decode_word(B256::with_last_byte(0xff), 0, ValueType::Int(8)),
+            Ok(DecodedValue::Int(I256::MINUS_ONE))
+        );
+
+        let mut short = [0u8; 32];
+        short[..4].copy_from_slice(b"USDC");
+        short[31] = 8;
+        assert_eq!(
+            decode_word(short.into(), 0, ValueType::String),
+            Ok(DecodedValue::String("USDC".into()))
+        );
+        let long = StringSlot::decode(B256::with_last_byte(2 * 40 + 1)).unwrap();
+        assert_eq!(long, StringSlot::Long { len: 40 });
+        assert_eq!(long.data_slots(U256::from(3)).unwrap().len(), 2);
+
+        assert_eq!("t_uint8".parse(), Ok(ValueType::Uint(8)));
+        assert_eq!("bytes4".parse(), Ok(ValueType::FixedBytes(4)));
+        assert!("uint7".parse::<ValueType>().is_err());
+    }
+
+    #[test]
+    fn test_odd_uint_is_not_a_string() {
+        let odd: U256 = (U256::from(1) << 255) + U256::from(1);
+        assert_eq!(
+            StringSlot::decode(odd.into()),
+            Err(StorageError::StringLength(odd >> 1))
+        );
+        assert!(StringSlot::decode(B256::with_last_byte(7)).is_err());
+        assert!(StringSlot::Long { len: usize::MAX }
+            .data_slots(U256::ZERO)
+            .is_err());
+        assert!(decode_word(odd.into(), 0, ValueType::String).is_err());
+    }
+
+    #[test]
+    fn test_offset_must_fit_in_slot() {
+        let word = B256::repeat_byte(0xff);
+        assert_eq!(
+            decode_word(word, 12, ValueType::Address),
+            Ok(DecodedValue::Address(Address::repeat_byte(0xff)))
+        );
+        assert_eq!(
+            decode_word(word, 40, ValueType::Uint(256)),
+            Err(StorageError::Offset {
+                offset: 40,
+                size: 32
+            })
+        );
+        assert!(decode_word(word, 13, ValueType::Address).is_err());
+        assert!(extract(word, usize::MAX, 1).is_err());
+    }
+}
@@ -0,0 +1,263 @@
+/* Generated by cbindgen from scroller-rs/scroller-ffi. Do not edit. */
+
+#ifndef SCROLLER_H
+#define SCROLLER_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum ScrollerStatus {
+  SCROLLER_STATUS_OK = 0,
+  /**
+   * A pointer was null, a string not UTF-8, or a value out of range.
+   */
+  SCROLLER_STATUS_INVALID_ARGUMENT = 1,
+  /**
+   * The RPC call failed or returned something that could not be decoded.
+   */
+  SCROLLER_STATUS_RPC = 2,
+  /**
+   * A bug in scroller-rs; the handle should not be used again.
+   */
+  SCROLLER_STATUS_PANIC = 3,
+} ScrollerStatus;
+
+/**
+ * Opaque handle to a `Scroller`, created by [`scroller_new`] and released with
+ * [`scroller_free`].
+ */
+typedef struct ScrollerHandle ScrollerHandle;
+
+/**
+ * Bytes allocated by scroller-rs. `data` is null when `len` is 0.
+ */
+typedef struct ScrollerBuffer {
+  uint8_t *data;
+  size_t len;
+} ScrollerBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Message of the last failed call on this thread, or null. Valid until the next failing
+ * call on the same thread.
+ */
+const char *scroller_last_error(void);
+
+/**
+ * Creates a handle reading from `rpc_url` through the L1Scroller at `scroller_address`,
+ * or through the L1SLOAD precompile directly when it is null. A non-zero
+ * `cache_capacity` attaches an in-memory slot cache to [`scroller_read_words`].
+ *
+ * # Safety
+ *
+ * `rpc_url` must be a NUL-terminated string, `scroller_address` null or 20 readable
+ * bytes, and `out` a writable pointer.
+ */
+enum ScrollerStatus scroller_new(const char *rpc_url,
+                                 const uint8_t *scroller_address,
+                                 size_t cache_capacity,
+                                 struct ScrollerHandle **out);
+
+/**
+ * Releases a handle. Null is ignored.
+ *
+ * # Safety
+ *
+ * `handle` must come from [`scroller_new`] and not be used afterwards.
+ */
+void scroller_free(struct ScrollerHandle *handle);
+
+/**
+ * Releases a buffer returned by a read.
+ *
+ * # Safety
+ *
+ * `buffer` must come from a read and not be freed twice.
+ */
+void scroller_buffer_free(struct ScrollerBuffer buffer);
+
+/**
+ * Latest L1 block number known to L2.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle and `out` writable.
+ */
+enum ScrollerStatus scroller_l1_block_number(const struct ScrollerHandle *handle, uint64_t *out);
+
+/**
+ * Latest L2 block number.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle and `out` writable.
+ */
+enum ScrollerStatus scroller_l2_block_number(const struct ScrollerHandle *handle, uint64_t *out);
+
+/**
+ * Reads `slot_count` slots (32 bytes each in `slots`) of `l1_contract` through the
+ * L1SLOAD precompile, batched five per call and cached. Writes `32 * slot_count` bytes.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle, `l1_contract` 20 readable bytes, `slots`
+ * `32 * slot_count` readable bytes and `out` writable.
+ */
+enum ScrollerStatus scroller_read_words(const struct ScrollerHandle *handle,
+                                        const uint8_t *l1_contract,
+                                        const uint8_t *slots,
+                                        size_t slot_count,
+                                        struct ScrollerBuffer *out);
+
+/**
+ * `L1Scroller.readSlot`: the raw output of L1SLOAD for one slot.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+ * `out` writable.
+ */
+enum ScrollerStatus scroller_read_slot(const struct ScrollerHandle *handle,
+                                       const uint8_t *l1_contract,
+                                       const uint8_t *slot,
+                                       struct ScrollerBuffer *out);
+
+/**
+ * `L1Scroller.readMultipleSlots`: the raw output of L1SLOAD for up to five slots.
+ *
+ * # Safety
+ *
+ * As [`scroller_read_words`].
+ */
+enum ScrollerStatus scroller_read_multiple_slots(const struct ScrollerHandle *handle,
+                                                 const uint8_t *l1_contract,
+                                                 const uint8_t *slots,
+                                                 size_t slot_count,
+                                                 struct ScrollerBuffer *out);
+
+/**
+ * `L1Scroller.readUint`. Writes 32 bytes to `out`.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+ * `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_read_uint(const struct ScrollerHandle *handle,
+                                       const uint8_t *l1_contract,
+                                       const uint8_t *slot,
+                                       uint8_t *out);
+
+/**
+ * `L1Scroller.readAddress`. Writes 20 bytes to `out`.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+ * `out` 20 writable bytes.
+ */
+enum ScrollerStatus scroller_read_address(const struct ScrollerHandle *handle,
+                                          const uint8_t *l1_contract,
+                                          const uint8_t *slot,
+                                          uint8_t *out);
+
+/**
+ * `L1Scroller.readString`. The UTF-8 bytes are not NUL-terminated.
+ *
+ * # Safety
+ *
+ * `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+ * `out` writable.
+ */
+enum ScrollerStatus scroller_read_string(const struct ScrollerHandle *handle,
+                                         const uint8_t *l1_contract,
+                                         const uint8_t *slot,
+                                         struct ScrollerBuffer *out);
+
+/**
+ * Slot of `mapping[key]` for a mapping declared at `base`.
+ *
+ * # Safety
+ *
+ * `key` and `base` must be 32 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_mapping_slot(const uint8_t *key, const uint8_t *base, uint8_t *out);
+
+/**
+ * Slot of `mapping[key]` for an `address` key.
+ *
+ * # Safety
+ *
+ * `key` must be 20 and `base` 32 readable bytes, and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_address_mapping_slot(const uint8_t *key,
+                                                  const uint8_t *base,
+                                                  uint8_t *out);
+
+/**
+ * First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+ *
+ * # Safety
+ *
+ * `slot` must be 32 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_data_slot(const uint8_t *slot, uint8_t *out);
+
+/**
+ * Slot of `_balances[account]` in an OpenZeppelin ERC20.
+ *
+ * # Safety
+ *
+ * `account` must be 20 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_erc20_balance_slot(const uint8_t *account, uint8_t *out);
+
+/**
+ * Slot of `_allowances[owner][spender]` in an OpenZeppelin ERC20.
+ *
+ * # Safety
+ *
+ * `owner` and `spender` must be 20 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_erc20_allowance_slot(const uint8_t *owner,
+                                                  const uint8_t *spender,
+                                                  uint8_t *out);
+
+/**
+ * Slot of `_owners[token_id]` in an OpenZeppelin ERC721.
+ *
+ * # Safety
+ *
+ * `token_id` must be 32 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_erc721_owner_slot(const uint8_t *token_id, uint8_t *out);
+
+/**
+ * Slot of `_balances[owner]` in an OpenZeppelin ERC721.
+ *
+ * # Safety
+ *
+ * `owner` must be 20 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_erc721_balance_slot(const uint8_t *owner, uint8_t *out);
+
+/**
+ * Slot of `_tokenApprovals[token_id]` in an OpenZeppelin ERC721.
+ *
+ * # Safety
+ *
+ * `token_id` must be 32 readable and `out` 32 writable bytes.
+ */
+enum ScrollerStatus scroller_erc721_token_approval_slot(const uint8_t *token_id, uint8_t *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* SCROLLER_H */
@@ -0,0 +1,515 @@
+//! C ABI over scroller-rs, for services written in Go, C++ or anything else that can call C.
+//! `include/scroller.h` is generated from this file by cbindgen on every build.
+//!
+//! Conventions shared by every function:
+//! - Addresses are 20 bytes and `uint256` values (slots, results) 32 big-endian bytes.
+//! - Functions return a [`ScrollerStatus`]; on failure [`scroller_last_error`] describes
+//!   the error and outputs are left untouched.
+//! - Variable-length results are written to a [`ScrollerBuffer`], which the caller owns
+//!   and releases with [`scroller_buffer_free`].
+//! - A [`ScrollerHandle`] blocks the calling thread on reads and must not be used from two
+//!   threads at once.
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    ptr, slice,
+    sync::Arc,
+};
+
+use alloy::primitives::{Address, U256};
+use scroller_rs::{
+    blocking,
+    cache::{LruSlotCache, ReadOptions},
+    erc20, erc721,
+    error::L1ScrollerError,
+    storage,
+};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollerStatus {
+    Ok = 0,
+    /// A pointer was null, a string not UTF-8, or a value out of range.
+    InvalidArgument = 1,
+    /// The RPC call failed or returned something that could not be decoded.
+    Rpc = 2,
+    /// A bug in scroller-rs; the handle should not be used again.
+    Panic = 3,
+}
+
+/// Opaque handle to a `Scroller`, created by [`scroller_new`] and released with
+/// [`scroller_free`].
+pub struct ScrollerHandle(blocking::Scroller);
+
+/// Bytes allocated by scroller-rs. `data` is null when `len` is 0.
+#[repr(C)]
+pub struct ScrollerBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl ScrollerBuffer {
+    fn new(bytes: Vec<u8>) -> Self {
+        if bytes.is_empty() {
+            return Self {
+                data: ptr::null_mut(),
+                len: 0,
+            };
+        }
+        let bytes = Box::into_raw(bytes.into_boxed_slice());
+        Self {
+            data: bytes.cast(),
+            len: bytes.len(),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+struct Failure(ScrollerStatus, String);
+
+fn invalid(message: impl Into<String>) -> Failure {
+    Failure(ScrollerStatus::InvalidArgument, message.into())
+}
+
+fn rpc(err: L1ScrollerError) -> Failure {
+    Failure(ScrollerStatus::Rpc, err.to_string())
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Runs `f`, turning errors and panics into a status and the last error message.
+fn run(f: impl FnOnce() -> Result<(), Failure>) -> ScrollerStatus {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => ScrollerStatus::Ok,
+        Ok(Err(Failure(status, message))) => {
+            set_last_error(message);
+            status
+        }
+        Err(_) => {
+            set_last_error("panic in scroller-rs".into());
+            ScrollerStatus::Panic
+        }
+    }
+}
+
+unsafe fn scroller_of<'a>(
+    handle: *const ScrollerHandle,
+) -> Result<&'a blocking::Scroller, Failure> {
+    handle
+        .as_ref()
+        .map(|handle| &handle.0)
+        .ok_or_else(|| invalid("handle is null"))
+}
+
+unsafe fn bytes<'a>(data: *const u8, len: usize, name: &str) -> Result<&'a [u8], Failure> {
+    if data.is_null() {
+        return Err(invalid(format!("{name} is null")));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+unsafe fn address(data: *const u8, name: &str) -> Result<Address, Failure> {
+    Ok(Address::from_slice(bytes(data, 20, name)?))
+}
+
+unsafe fn word(data: *const u8, name: &str) -> Result<U256, Failure> {
+    Ok(U256::from_be_slice(bytes(data, 32, name)?))
+}
+
+unsafe fn words(data: *const u8, count: usize, name: &str) -> Result<Vec<U256>, Failure> {
+    let data = bytes(
+        data,
+        count
+            .checked_mul(32)
+            .ok_or_else(|| invalid("too many slots"))?,
+        name,
+    )?;
+    Ok(data.chunks_exact(32).map(U256::from_be_slice).collect())
+}
+
+unsafe fn write<T>(out: *mut T, value: T) -> Result<(), Failure> {
+    if out.is_null() {
+        return Err(invalid("out is null"));
+    }
+    out.write(value);
+    Ok(())
+}
+
+unsafe fn write_bytes(out: *mut u8, value: &[u8]) -> Result<(), Failure> {
+    if out.is_null() {
+        return Err(invalid("out is null"));
+    }
+    ptr::copy_nonoverlapping(value.as_ptr(), out, value.len());
+    Ok(())
+}
+
+/// Message of the last failed call on this thread, or null. Valid until the next failing
+/// call on the same thread.
+#[no_mangle]
+pub extern "C" fn scroller_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Creates a handle reading from `rpc_url` through the L1Scroller at `scroller_address`,
+/// or through the L1SLOAD precompile directly when it is null. A non-zero
+/// `cache_capacity` attaches an in-memory slot cache to [`scroller_read_words`].
+///
+/// # Safety
+///
+/// `rpc_url` must be a NUL-terminated string, `scroller_address` null or 20 readable
+/// bytes, and `out` a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_new(
+    rpc_url: *const c_char,
+    scroller_address: *const u8,
+    cache_capacity: usize,
+    out: *mut *mut ScrollerHandle,
+) -> ScrollerStatus {
+    run(|| {
+        if rpc_url.is_null() {
+            return Err(invalid("rpc_url is null"));
+        }
+        let rpc_url = CStr::from_ptr(rpc_url)
+            .to_str()
+            .map_err(|_| invalid("rpc_url is not UTF-8"))?;
+        let scroller = match scroller_address.is_null() {
+            true => blocking::Scroller::direct(rpc_url),
+            false => {
+                blocking::Scroller::new(rpc_url, address(scroller_address, "scroller_address")?)
+            }
+        };
+        let mut scroller = scroller.map_err(|err| invalid(err.to_string()))?;
+        if cache_capacity > 0 {
+            scroller = scroller.with_cache(Arc::new(LruSlotCache::new(cache_capacity)));
+        }
+        write(out, Box::into_raw(Box::new(ScrollerHandle(scroller))))
+    })
+}
+
+/// Releases a handle. Null is ignored.
+///
+/// # Safety
+///
+/// `handle` must come from [`scroller_new`] and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_free(handle: *mut ScrollerHandle) {
+    if !handle.is_null() {
+        drop(Box::from_raw(handle));
+    }
+}
+
+/// Releases a buffer returned by a read.
+///
+/// # Safety
+///
+/// `buffer` must come from a read and not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_buffer_free(buffer: ScrollerBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+            buffer.data,
+            buffer.len,
+        )));
+    }
+}
+
+/// Latest L1 block number known to L2.
+///
+/// # Safety
+///
+/// `handle` must be a live handle and `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_l1_block_number(
+    handle: *const ScrollerHandle,
+    out: *mut u64,
+) -> ScrollerStatus {
+    run(|| write(out, scroller_of(handle)?.l1_block_number().map_err(rpc)?))
+}
+
+/// Latest L2 block number.
+///
+/// # Safety
+///
+/// `handle` must be a live handle and `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_l2_block_number(
+    handle: *const ScrollerHandle,
+    out: *mut u64,
+) -> ScrollerStatus {
+    run(|| write(out, scroller_of(handle)?.l2_block_number().map_err(rpc)?))
+}
+
+/// Reads `slot_count` slots (32 bytes each in `slots`) of `l1_contract` through the
+/// L1SLOAD precompile, batched five per call and cached. Writes `32 * slot_count` bytes.
+///
+/// # Safety
+///
+/// `handle` must be a live handle, `l1_contract` 20 readable bytes, `slots`
+/// `32 * slot_count` readable bytes and `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_words(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slots: *const u8,
+    slot_count: usize,
+    out: *mut ScrollerBuffer,
+) -> ScrollerStatus {
+    run(|| {
+        let values = scroller_of(handle)?
+            .read_words(
+                address(l1_contract, "l1_contract")?,
+                &words(slots, slot_count, "slots")?,
+                ReadOptions::default(),
+            )
+            .map_err(rpc)?;
+        write(out, ScrollerBuffer::new(values.concat()))
+    })
+}
+
+/// `L1Scroller.readSlot`: the raw output of L1SLOAD for one slot.
+///
+/// # Safety
+///
+/// `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+/// `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_slot(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slot: *const u8,
+    out: *mut ScrollerBuffer,
+) -> ScrollerStatus {
+    run(|| {
+        let value = scroller_of(handle)?
+            .read_slot(address(l1_contract, "l1_contract")?, word(slot, "slot")?)
+            .map_err(rpc)?;
+        write(out, ScrollerBuffer::new(value.to_vec()))
+    })
+}
+
+/// `L1Scroller.readMultipleSlots`: the raw output of L1SLOAD for up to five slots.
+///
+/// # Safety
+///
+/// As [`scroller_read_words`].
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_multiple_slots(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slots: *const u8,
+    slot_count: usize,
+    out: *mut ScrollerBuffer,
+) -> ScrollerStatus {
+    run(|| {
+        let value = scroller_of(handle)?
+            .read_multiple_slots(
+                address(l1_contract, "l1_contract")?,
+                words(slots, slot_count, "slots")?,
+            )
+            .map_err(rpc)?;
+        write(out, ScrollerBuffer::new(value.to_vec()))
+    })
+}
+
+/// `L1Scroller.readUint`. Writes 32 bytes to `out`.
+///
+/// # Safety
+///
+/// `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+/// `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_uint(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slot: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let value = scroller_of(handle)?
+            .read_uint(address(l1_contract, "l1_contract")?, word(slot, "slot")?)
+            .map_err(rpc)?;
+        write_bytes(out, &value.to_be_bytes::<32>())
+    })
+}
+
+/// `L1Scroller.readAddress`. Writes 20 bytes to `out`.
+///
+/// # Safety
+///
+/// `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+/// `out` 20 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_address(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slot: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let value = scroller_of(handle)?
+            .read_address(address(l1_contract, "l1_contract")?, word(slot, "slot")?)
+            .map_err(rpc)?;
+        write_bytes(out, value.as_slice())
+    })
+}
+
+/// `L1Scroller.readString`. The UTF-8 bytes are not NUL-terminated.
+///
+/// # Safety
+///
+/// `handle` must be a live handle, `l1_contract` 20 and `slot` 32 readable bytes, and
+/// `out` writable.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_read_string(
+    handle: *const ScrollerHandle,
+    l1_contract: *const u8,
+    slot: *const u8,
+    out: *mut ScrollerBuffer,
+) -> ScrollerStatus {
+    run(|| {
+        let value = scroller_of(handle)?
+            .read_string(address(l1_contract, "l1_contract")?, word(slot, "slot")?)
+            .map_err(rpc)?;
+        write(out, ScrollerBuffer::new(value.into_bytes()))
+    })
+}
+
+/// Slot of `mapping[key]` for a mapping declared at `base`.
+///
+/// # Safety
+///
+/// `key` and `base` must be 32 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_mapping_slot(
+    key: *const u8,
+    base: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = storage::mapping_slot(word(key, "key")?.into(), word(base, "base")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `mapping[key]` for an `address` key.
+///
+/// # Safety
+///
+/// `key` must be 20 and `base` 32 readable bytes, and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_address_mapping_slot(
+    key: *const u8,
+    base: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = storage::address_mapping_slot(address(key, "key")?, word(base, "base")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+///
+/// # Safety
+///
+/// `slot` must be 32 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_data_slot(slot: *const u8, out: *mut u8) -> ScrollerStatus {
+    run(|| {
+        let slot = storage::data_slot(word(slot, "slot")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `_balances[account]` in an OpenZeppelin ERC20.
+///
+/// # Safety
+///
+/// `account` must be 20 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_erc20_balance_slot(
+    account: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = erc20::balance_slot(address(account, "account")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `_allowances[owner][spender]` in an OpenZeppelin ERC20.
+///
+/// # Safety
+///
+/// `owner` and `spender` must be 20 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_erc20_allowance_slot(
+    owner: *const u8,
+    spender: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = erc20::allowance_slot(address(owner, "owner")?, address(spender, "spender")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `_owners[token_id]` in an OpenZeppelin ERC721.
+///
+/// # Safety
+///
+/// `token_id` must be 32 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_erc721_owner_slot(
+    token_id: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = erc721::owner_slot(word(token_id, "token_id")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `_balances[owner]` in an OpenZeppelin ERC721.
+///
+/// # Safety
+///
+/// `owner` must be 20 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_erc721_balance_slot(
+    owner: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = erc721::balance_slot(address(owner, "owner")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
+
+/// Slot of `_tokenApprovals[token_id]` in an OpenZeppelin ERC721.
+///
+/// # Safety
+///
+/// `token_id` must be 32 readable and `out` 32 writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn scroller_erc721_token_approval_slot(
+    token_id: *const u8,
+    out: *mut u8,
+) -> ScrollerStatus {
+    run(|| {
+        let slot = erc721::token_approval_slot(word(token_id, "token_id")?);
+        write_bytes(out, &slot.to_be_bytes::<32>())
+    })
+}
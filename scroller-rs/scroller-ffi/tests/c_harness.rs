@@ -0,0 +1,40 @@
+//! Builds `tests/harness.c` against the freshly built `libscroller_ffi` and runs it.
+
+use std::{path::PathBuf, process::Command};
+
+#[test]
+fn c_harness_passes() {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // target/<profile>/deps/c_harness-<hash> -> target/<profile>
+    let lib_dir = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .and_then(|deps| deps.parent())
+        .unwrap()
+        .to_path_buf();
+    let binary = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("scroller_harness");
+
+    let status = Command::new(std::env::var("CC").unwrap_or_else(|_| "cc".into()))
+        .arg(manifest_dir.join("tests/harness.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg("-lscroller_ffi")
+        .arg("-o")
+        .arg(&binary)
+        .status()
+        .expect("a C compiler is required to run the harness");
+    assert!(status.success(), "compiling harness.c failed");
+
+    let output = Command::new(&binary)
+        .env("LD_LIBRARY_PATH", &lib_dir)
+        .env("DYLD_LIBRARY_PATH", &lib_dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "harness failed:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,95 @@
+/* Exercises the C API the way a C caller would; exits non-zero on the first failure. */
+#include <stdio.h>
+#include <string.h>
+
+#include "scroller.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                  \
+  do {                                                               \
+    if (!(cond)) {                                                   \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__,         \
+              __LINE__, #cond);                                      \
+      failures++;                                                    \
+    }                                                                \
+  } while (0)
+
+static void slot_calculations(void) {
+  /* keccak256(uint256(0)) */
+  static const uint8_t expected_data_slot[32] = {
+      0x29, 0x0d, 0xec, 0xd9, 0x54, 0x8b, 0x62, 0xa8, 0xd6, 0x03, 0x45,
+      0xa9, 0x88, 0x38, 0x6f, 0xc8, 0x4b, 0xa6, 0xbc, 0x95, 0x48, 0x40,
+      0x08, 0xf6, 0x36, 0x2f, 0x93, 0x16, 0x0e, 0xf3, 0xe5, 0x63};
+  uint8_t zero[32] = {0};
+  uint8_t out[32];
+  CHECK(scroller_data_slot(zero, out) == SCROLLER_STATUS_OK);
+  CHECK(memcmp(out, expected_data_slot, 32) == 0);
+
+  uint8_t account[20];
+  memset(account, 0xab, sizeof account);
+  uint8_t balance[32], mapping[32];
+  CHECK(scroller_erc20_balance_slot(account, balance) == SCROLLER_STATUS_OK);
+  CHECK(scroller_address_mapping_slot(account, zero, mapping) ==
+        SCROLLER_STATUS_OK);
+  CHECK(memcmp(balance, mapping, 32) == 0);
+
+  uint8_t allowance[32];
+  CHECK(scroller_erc20_allowance_slot(account, account, allowance) ==
+        SCROLLER_STATUS_OK);
+  CHECK(memcmp(allowance, balance, 32) != 0);
+}
+
+static void invalid_arguments(void) {
+  uint8_t out[32];
+  CHECK(scroller_data_slot(NULL, out) == SCROLLER_STATUS_INVALID_ARGUMENT);
+  CHECK(scroller_last_error() != NULL);
+  CHECK(strstr(scroller_last_error(), "slot") != NULL);
+
+  ScrollerHandle *handle = NULL;
+  CHECK(scroller_new(NULL, NULL, 0, &handle) ==
+        SCROLLER_STATUS_INVALID_ARGUMENT);
+  CHECK(handle == NULL);
+  CHECK(scroller_new("not a url", NULL, 0, &handle) ==
+        SCROLLER_STATUS_INVALID_ARGUMENT);
+  CHECK(handle == NULL);
+
+  uint64_t block;
+  CHECK(scroller_l2_block_number(NULL, &block) ==
+        SCROLLER_STATUS_INVALID_ARGUMENT);
+  scroller_free(NULL);
+}
+
+static void unreachable_rpc(void) {
+  ScrollerHandle *handle = NULL;
+  CHECK(scroller_new("http://127.0.0.1:1", NULL, 16, &handle) ==
+        SCROLLER_STATUS_OK);
+  CHECK(handle != NULL);
+  if (handle == NULL) return;
+
+  uint8_t contract[20] = {0};
+  uint8_t slots[64] = {0};
+  ScrollerBuffer buffer = {NULL, 0};
+  CHECK(scroller_read_words(handle, contract, slots, 2, &buffer) ==
+        SCROLLER_STATUS_RPC);
+  CHECK(buffer.data == NULL);
+  CHECK(scroller_last_error() != NULL &&
+        strlen(scroller_last_error()) > 0);
+  scroller_buffer_free(buffer);
+
+  uint8_t value[32];
+  CHECK(scroller_read_uint(handle, contract, slots, value) ==
+        SCROLLER_STATUS_RPC);
+  CHECK(scroller_read_uint(handle, contract, slots, NULL) !=
+        SCROLLER_STATUS_OK);
+
+  scroller_free(handle);
+}
+
+int main(void) {
+  slot_calculations();
+  invalid_arguments();
+  unreachable_rpc();
+  if (failures == 0) printf("ok\n");
+  return failures == 0 ? 0 : 1;
+}
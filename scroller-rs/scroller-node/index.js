@@ -0,0 +1,2 @@
+// Built by `npm run build` (`napi build --release`), which writes scroller.node here.
+module.exports = require("./scroller.node");
@@ -0,0 +1,431 @@
+//! Node.js bindings for scroller-rs, built with `napi build` as `scroller.node`.
+//!
+//! Slots and integer results are `BigInt`s, addresses are hex strings, and raw words are
+//! `Buffer`s. Every read returns a `Promise`.
+//!
+//! ```js
+//! const { Scroller, erc20BalanceSlot, decodeWord } = require("scroller-node");
+//!
+//! const scroller = new Scroller("https://l1sload-rpc.scroll.io", null, 10000);
+//! const slot = erc20BalanceSlot("0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+//! const [word] = await scroller.readWords("0xA8E50c2607678747D9d8A24AC52234712bE41fD9", [slot]);
+//! console.log(decodeWord(word, "uint256"));
+//! ```
+
+#![deny(clippy::all)]
+
+use std::sync::Arc;
+
+use alloy::primitives::{Address, B256, I256, U256};
+use napi::{
+    bindgen_prelude::{BigInt, Buffer},
+    Env, Error, JsUnknown, Result,
+};
+use napi_derive::napi;
+use scroller_rs::{
+    cache::{LruSlotCache, ReadOptions, SlotCache},
+    disk_cache::DiskSlotCache,
+    erc20, erc721,
+    l1scroller::{self, L1ScrollerContracttype},
+    storage::{self, DecodedValue, ValueType},
+};
+
+fn error(err: impl std::fmt::Display) -> Error {
+    Error::from_reason(err.to_string())
+}
+
+fn to_u256(value: BigInt) -> Result<U256> {
+    if value.sign_bit || value.words.len() > 4 {
+        return Err(error("expected a uint256 BigInt"));
+    }
+    let mut limbs = [0u64; 4];
+    limbs[..value.words.len()].copy_from_slice(&value.words);
+    Ok(U256::from_limbs(limbs))
+}
+
+fn to_bigint(value: U256) -> BigInt {
+    BigInt {
+        sign_bit: false,
+        words: value.as_limbs().to_vec(),
+    }
+}
+
+fn from_i256(value: I256) -> BigInt {
+    BigInt {
+        sign_bit: value.is_negative(),
+        words: value.unsigned_abs().as_limbs().to_vec(),
+    }
+}
+
+fn to_address(value: &str) -> Result<Address> {
+    value
+        .parse()
+        .map_err(|_| error(format!("invalid address `{value}`")))
+}
+
+fn to_slots(slots: Vec<BigInt>) -> Result<Vec<U256>> {
+    slots.into_iter().map(to_u256).collect()
+}
+
+fn to_word(word: &[u8]) -> Result<B256> {
+    B256::try_from(word).map_err(|_| error(format!("expected 32 bytes, got {}", word.len())))
+}
+
+fn read_options(bypass_cache: Option<bool>, immutable: Option<bool>) -> ReadOptions {
+    ReadOptions {
+        bypass_cache: bypass_cache.unwrap_or_default(),
+        immutable: immutable.unwrap_or_default(),
+        ..Default::default()
+    }
+}
+
+#[napi(object)]
+pub struct CacheStats {
+    pub hits: i64,
+    pub misses: i64,
+    pub inserts: i64,
+    pub evictions: i64,
+    pub expirations: i64,
+    pub errors: i64,
+    pub entries: i64,
+    pub hit_rate: f64,
+}
+
+/// Reads L1 contract storage from Scroll.
+#[napi]
+pub struct Scroller {
+    inner: Arc<l1scroller::Scroller<L1ScrollerContracttype>>,
+}
+
+#[napi]
+impl Scroller {
+    /// Reads through the L1Scroller deployed at `scrollerAddress`, or calls the L1SLOAD
+    /// precompile directly when it is not given. `cacheCapacity` or `cachePath` attach an
+    /// in-memory or on-disk slot cache to `readWords` and `readWord`.
+    #[napi(constructor)]
+    pub fn new(
+        rpc_url: String,
+        scroller_address: Option<String>,
+        cache_capacity: Option<u32>,
+        cache_path: Option<String>,
+    ) -> Result<Self> {
+        let mut scroller = match scroller_address {
+            Some(address) => l1scroller::Scroller::new(&rpc_url, to_address(&address)?),
+            None => l1scroller::Scroller::direct(&rpc_url),
+        }
+        .map_err(error)?;
+        let cache: Option<Arc<dyn SlotCache>> = match (cache_capacity, cache_path) {
+            (_, Some(path)) => Some(Arc::new(DiskSlotCache::open(path).map_err(error)?)),
+            (Some(capacity), None) => Some(Arc::new(LruSlotCache::new(capacity as usize))),
+            (None, None) => None,
+        };
+        if let Some(cache) = cache {
+            scroller = scroller.with_cache(cache);
+        }
+        Ok(Self {
+            inner: Arc::new(scroller),
+        })
+    }
+
+    #[napi(getter)]
+    pub fn scroller_address(&self) -> Option<String> {
+        self.inner
+            .scroller_address()
+            .map(|address| address.to_string())
+    }
+
+    /// Cache statistics, or `null` without a cache.
+    #[napi]
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats().map(|stats| CacheStats {
+            hits: stats.hits as i64,
+            misses: stats.misses as i64,
+            inserts: stats.inserts as i64,
+            evictions: stats.evictions as i64,
+            expirations: stats.expirations as i64,
+            errors: stats.errors as i64,
+            entries: stats.entries as i64,
+            hit_rate: stats.hit_rate(),
+        })
+    }
+
+    #[napi]
+    pub async fn l1_block_number(&self) -> Result<i64> {
+        let block = self.inner.l1_block_number().await.map_err(error)?;
+        Ok(block as i64)
+    }
+
+    #[napi]
+    pub async fn l2_block_number(&self) -> Result<i64> {
+        let block = self.inner.l2_block_number().await.map_err(error)?;
+        Ok(block as i64)
+    }
+
+    /// Raw words of any number of slots through the L1SLOAD precompile, batched five per
+    /// call and served from the cache when one is attached.
+    #[napi]
+    pub async fn read_words(
+        &self,
+        l1_contract: String,
+        slots: Vec<BigInt>,
+        bypass_cache: Option<bool>,
+        immutable: Option<bool>,
+    ) -> Result<Vec<Buffer>> {
+        let words = self
+            .inner
+            .read_words(
+                to_address(&l1_contract)?,
+                &to_slots(slots)?,
+                read_options(bypass_cache, immutable),
+            )
+            .await
+            .map_err(error)?;
+        Ok(words.iter().map(|word| word.to_vec().into()).collect())
+    }
+
+    #[napi]
+    pub async fn read_word(
+        &self,
+        l1_contract: String,
+        slot: BigInt,
+        bypass_cache: Option<bool>,
+        immutable: Option<bool>,
+    ) -> Result<Buffer> {
+        let word = self
+            .inner
+            .read_word(
+                to_address(&l1_contract)?,
+                to_u256(slot)?,
+                read_options(bypass_cache, immutable),
+            )
+            .await
+            .map_err(error)?;
+        Ok(word.to_vec().into())
+    }
+
+    #[napi]
+    pub async fn read_slot(&self, l1_contract: String, slot: BigInt) -> Result<Buffer> {
+        let bytes = self
+            .inner
+            .read_slot(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(bytes.to_vec().into())
+    }
+
+    #[napi]
+    pub async fn read_multiple_slots(
+        &self,
+        l1_contract: String,
+        slots: Vec<BigInt>,
+    ) -> Result<Buffer> {
+        let bytes = self
+            .inner
+            .read_multiple_slots(to_address(&l1_contract)?, to_slots(slots)?)
+            .await
+            .map_err(error)?;
+        Ok(bytes.to_vec().into())
+    }
+
+    #[napi]
+    pub async fn read_uint(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(value))
+    }
+
+    #[napi]
+    pub async fn read_uint160(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint160(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint128(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint128(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint96(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint96(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint64(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint64(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint48(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint48(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint32(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint32(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint24(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint24(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_uint8(&self, l1_contract: String, slot: BigInt) -> Result<BigInt> {
+        let value = self
+            .inner
+            .read_uint8(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(to_bigint(U256::from(value)))
+    }
+
+    #[napi]
+    pub async fn read_address(&self, l1_contract: String, slot: BigInt) -> Result<String> {
+        let address = self
+            .inner
+            .read_address(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)?;
+        Ok(address.to_string())
+    }
+
+    #[napi]
+    pub async fn read_string(&self, l1_contract: String, slot: BigInt) -> Result<String> {
+        self.inner
+            .read_string(to_address(&l1_contract)?, to_u256(slot)?)
+            .await
+            .map_err(error)
+    }
+}
+
+/// Decodes the value of Solidity type `ty` stored `offset` bytes from the right of a
+/// 32-byte `word`, e.g. one returned by `Scroller.readWords`.
+#[napi]
+pub fn decode_word(env: Env, word: Buffer, ty: String, offset: Option<u32>) -> Result<JsUnknown> {
+    let ty: ValueType = ty.parse().map_err(error)?;
+    let value =
+        storage::decode_word(to_word(&word)?, offset.unwrap_or(0) as usize, ty).map_err(error)?;
+    Ok(match value {
+        DecodedValue::Uint(value) => env
+            .create_bigint_from_words(false, value.as_limbs().to_vec())?
+            .into_unknown()?,
+        DecodedValue::Int(value) => {
+            let value = from_i256(value);
+            env.create_bigint_from_words(value.sign_bit, value.words)?
+                .into_unknown()?
+        }
+        DecodedValue::Address(value) => env.create_string(&value.to_string())?.into_unknown(),
+        DecodedValue::Bool(value) => env.get_boolean(value)?.into_unknown(),
+        DecodedValue::Bytes(value) => env.create_buffer_with_data(value.to_vec())?.into_unknown(),
+        DecodedValue::String(value) => env.create_string(&value)?.into_unknown(),
+    })
+}
+
+/// Slot of `mapping[key]` for a mapping declared at `base`.
+#[napi]
+pub fn mapping_slot(key: BigInt, base: BigInt) -> Result<BigInt> {
+    Ok(to_bigint(storage::mapping_slot(
+        to_u256(key)?.into(),
+        to_u256(base)?,
+    )))
+}
+
+#[napi]
+pub fn address_mapping_slot(key: String, base: BigInt) -> Result<BigInt> {
+    Ok(to_bigint(storage::address_mapping_slot(
+        to_address(&key)?,
+        to_u256(base)?,
+    )))
+}
+
+/// First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+#[napi]
+pub fn data_slot(slot: BigInt) -> Result<BigInt> {
+    Ok(to_bigint(storage::data_slot(to_u256(slot)?)))
+}
+
+#[napi]
+pub fn erc20_balance_slot(account: String) -> Result<BigInt> {
+    Ok(to_bigint(erc20::balance_slot(to_address(&account)?)))
+}
+
+#[napi]
+pub fn erc20_allowance_slot(owner: String, spender: String) -> Result<BigInt> {
+    Ok(to_bigint(erc20::allowance_slot(
+        to_address(&owner)?,
+        to_address(&spender)?,
+    )))
+}
+
+#[napi]
+pub fn erc721_owner_slot(token_id: BigInt) -> Result<BigInt> {
+    Ok(to_bigint(erc721::owner_slot(to_u256(token_id)?)))
+}
+
+#[napi]
+pub fn erc721_balance_slot(owner: String) -> Result<BigInt> {
+    Ok(to_bigint(erc721::balance_slot(to_address(&owner)?)))
+}
+
+#[napi]
+pub fn erc721_token_approval_slot(token_id: BigInt) -> Result<BigInt> {
+    Ok(to_bigint(erc721::token_approval_slot(to_u256(token_id)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bigint_round_trip() {
+        let value = U256::MAX - U256::from(7);
+        assert_eq!(to_u256(to_bigint(value)).unwrap(), value);
+        assert!(to_u256(BigInt {
+            sign_bit: true,
+            words: vec![1],
+        })
+        .is_err());
+
+        let negative = from_i256(I256::try_from(-5).unwrap());
+        assert!(negative.sign_bit);
+        assert_eq!(negative.words[0], 5);
+    }
+}
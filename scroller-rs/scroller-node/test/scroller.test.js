@@ -0,0 +1,33 @@
+const test = require("node:test");
+const assert = require("node:assert");
+
+const scroller = require("..");
+
+const ACCOUNT = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC";
+
+test("slot calculators", () => {
+    assert.strictEqual(scroller.erc20BalanceSlot(ACCOUNT), scroller.addressMappingSlot(ACCOUNT, 0n));
+    assert.strictEqual(
+        scroller.dataSlot(0n),
+        0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563n
+    );
+    assert.throws(() => scroller.dataSlot(-1n));
+});
+
+test("decodeWord", () => {
+    const word = Buffer.alloc(32);
+    word.writeUInt8(5, 11);
+    word.writeUInt8(0xff, 31);
+    assert.strictEqual(scroller.decodeWord(word, "uint96", 20), 5n);
+    assert.strictEqual(scroller.decodeWord(word, "int8"), -1n);
+    assert.strictEqual(scroller.decodeWord(word, "bool"), true);
+    assert.throws(() => scroller.decodeWord(word, "uint7"));
+    assert.throws(() => scroller.decodeWord(word, "uint256", 1));
+});
+
+test("failed reads reject", async () => {
+    const s = new scroller.Scroller("http://127.0.0.1:1", null, 16);
+    assert.strictEqual(s.scrollerAddress, null);
+    assert.strictEqual(s.cacheStats().entries, 0);
+    await assert.rejects(s.readUint(ACCOUNT, 0n));
+});
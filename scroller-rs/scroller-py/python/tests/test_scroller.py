@@ -0,0 +1,30 @@
+import asyncio
+
+import pytest
+
+import scroller
+
+ACCOUNT = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC"
+
+
+def test_slots():
+    assert scroller.erc20_balance_slot(ACCOUNT) == scroller.address_mapping_slot(ACCOUNT, 0)
+    assert scroller.mapping_slot(1, 0) == scroller.mapping_slot("0x1", (0).to_bytes(32, "big"))
+    with pytest.raises(ValueError):
+        scroller.data_slot("not a slot")
+
+
+def test_decode_word():
+    word = (5).to_bytes(12, "big") + bytes(19) + b"\xff"
+    assert scroller.decode_word(word, "uint96", 20) == 5
+    assert scroller.decode_word(word, "uint8") == 255
+
+
+def test_read_error():
+    s = scroller.Scroller("http://127.0.0.1:1")
+
+    async def read():
+        return await s.read_uint(ACCOUNT, 0)
+
+    with pytest.raises(scroller.ScrollerError):
+        asyncio.run(read())
@@ -0,0 +1,535 @@
+//! Python bindings for scroller-rs, built with maturin as the `scroller` module.
+//!
+//! ```python
+//! import asyncio, scroller
+//!
+//! async def main():
+//!     s = scroller.Scroller("https://l1sload-rpc.scroll.io", cache_capacity=10_000)
+//!     slot = scroller.erc20_balance_slot("0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC")
+//!     words = await s.read_words("0xA8E50c2607678747D9d8A24AC52234712bE41fD9", [slot, 2])
+//!     print(scroller.decode_word(words[0], "uint256"))
+//!
+//! asyncio.run(main())
+//! ```
+
+use std::{future::Future, sync::Arc};
+
+use alloy::primitives::{Address, B256, U256};
+use pyo3::{
+    create_exception,
+    exceptions::{PyException, PyValueError},
+    prelude::*,
+    types::{PyBytes, PyInt},
+};
+use scroller_rs::{
+    cache::{LruSlotCache, ReadOptions, SlotCache},
+    disk_cache::DiskSlotCache,
+    erc20, erc721,
+    error::L1ScrollerError,
+    l1scroller::{self, L1ScrollerContracttype},
+    storage::{self, DecodedValue, ValueType},
+};
+
+create_exception!(scroller, ScrollerError, PyException);
+
+fn error(err: impl std::fmt::Display) -> PyErr {
+    ScrollerError::new_err(err.to_string())
+}
+
+/// A `uint256` given as a Python `int`, a decimal or `0x` string, or 32 big-endian bytes.
+struct Word(U256);
+
+impl<'py> FromPyObject<'py> for Word {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(bytes) = obj.downcast::<PyBytes>() {
+            let bytes = bytes.as_bytes();
+            return match <[u8; 32]>::try_from(bytes) {
+                Ok(word) => Ok(Word(U256::from_be_bytes(word))),
+                Err(_) => Err(PyValueError::new_err(format!(
+                    "expected 32 bytes, got {}",
+                    bytes.len()
+                ))),
+            };
+        }
+        let text = match obj.is_instance_of::<PyInt>() {
+            true => obj.str()?.to_string(),
+            false => obj.extract::<String>()?,
+        };
+        text.parse()
+            .map(Word)
+            .map_err(|_| PyValueError::new_err(format!("invalid uint256 `{text}`")))
+    }
+}
+
+/// An address given as a hex string.
+struct PyAddress(Address);
+
+impl<'py> FromPyObject<'py> for PyAddress {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let text: String = obj.extract()?;
+        text.parse()
+            .map(PyAddress)
+            .map_err(|_| PyValueError::new_err(format!("invalid address `{text}`")))
+    }
+}
+
+/// Converts to a Python `int`.
+struct Int(U256);
+
+impl<'py> IntoPyObject<'py> for Int {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        py.get_type::<PyInt>().call1((self.0.to_string(),))
+    }
+}
+
+/// Converts a decoded storage value to the matching Python type.
+struct Value(DecodedValue);
+
+impl<'py> IntoPyObject<'py> for Value {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        match self.0 {
+            DecodedValue::Uint(value) => Int(value).into_pyobject(py),
+            DecodedValue::Int(value) => py.get_type::<PyInt>().call1((value.to_string(),)),
+            DecodedValue::Address(value) => Ok(value.to_string().into_pyobject(py)?.into_any()),
+            DecodedValue::Bool(value) => Ok(value.into_pyobject(py)?.to_owned().into_any()),
+            DecodedValue::Bytes(value) => Ok(PyBytes::new(py, &value).into_any()),
+            DecodedValue::String(value) => Ok(value.into_pyobject(py)?.into_any()),
+        }
+    }
+}
+
+fn words(words: Vec<B256>) -> Vec<Vec<u8>> {
+    words.into_iter().map(|word| word.to_vec()).collect()
+}
+
+/// Reads L1 contract storage from Scroll. Every read method returns an awaitable.
+#[pyclass(frozen)]
+struct Scroller {
+    inner: Arc<l1scroller::Scroller<L1ScrollerContracttype>>,
+}
+
+impl Scroller {
+    /// Runs `read` on the shared tokio runtime and returns it as a Python awaitable.
+    fn spawn<'py, T, F>(
+        &self,
+        py: Python<'py>,
+        read: impl FnOnce(Arc<l1scroller::Scroller<L1ScrollerContracttype>>) -> F,
+    ) -> PyResult<Bound<'py, PyAny>>
+    where
+        F: Future<Output = Result<T, L1ScrollerError>> + Send + 'static,
+        T: for<'a> IntoPyObject<'a> + Send + 'static,
+    {
+        let read = read(self.inner.clone());
+        pyo3_async_runtimes::tokio::future_into_py(py, async move { read.await.map_err(error) })
+    }
+}
+
+#[pymethods]
+impl Scroller {
+    /// Reads through the L1Scroller deployed at `scroller_address`, or calls the L1SLOAD
+    /// precompile directly when it is not given. `cache_capacity` or `cache_path` attach
+    /// an in-memory or on-disk slot cache to `read_words` and `read_word`; passing both
+    /// raises `ValueError`.
+    #[new]
+    #[pyo3(signature = (rpc_url, scroller_address=None, cache_capacity=None, cache_path=None))]
+    fn new(
+        rpc_url: &str,
+        scroller_address: Option<PyAddress>,
+        cache_capacity: Option<usize>,
+        cache_path: Option<&str>,
+    ) -> PyResult<Self> {
+        let mut scroller = match scroller_address {
+            Some(address) => l1scroller::Scroller::new(rpc_url, address.0),
+            None => l1scroller::Scroller::direct(rpc_url),
+        }
+        .map_err(error)?;
+        let cache: Option<Arc<dyn SlotCache>> = match (cache_capacity, cache_path) {
+            (Some(_), Some(_)) => {
+                return Err(PyValueError::new_err(
+                    "cache_capacity and cache_path are mutually exclusive",
+                ))
+            }
+            (None, Some(path)) => Some(Arc::new(DiskSlotCache::open(path).map_err(error)?)),
+            (Some(capacity), None) => Some(Arc::new(LruSlotCache::new(capacity))),
+            (None, None) => None,
+        };
+        if let Some(cache) = cache {
+            scroller = scroller.with_cache(cache);
+        }
+        Ok(Self {
+            inner: Arc::new(scroller),
+        })
+    }
+
+    #[getter]
+    fn scroller_address(&self) -> Option<String> {
+        self.inner
+            .scroller_address()
+            .map(|address| address.to_string())
+    }
+
+    /// Cache statistics as a dict, or `None` without a cache.
+    fn cache_stats(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let Some(stats) = self.inner.cache_stats() else {
+            return Ok(None);
+        };
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("hits", stats.hits)?;
+        dict.set_item("misses", stats.misses)?;
+        dict.set_item("inserts", stats.inserts)?;
+        dict.set_item("evictions", stats.evictions)?;
+        dict.set_item("expirations", stats.expirations)?;
+        dict.set_item("errors", stats.errors)?;
+        dict.set_item("entries", stats.entries)?;
+        dict.set_item("hit_rate", stats.hit_rate())?;
+        Ok(Some(dict.into_any().unbind()))
+    }
+
+    fn l1_block_number<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move { s.l1_block_number().await })
+    }
+
+    fn l2_block_number<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move { s.l2_block_number().await })
+    }
+
+    /// Raw words of any number of slots through the L1SLOAD precompile, batched five per
+    /// call and served from the cache when one is attached.
+    #[pyo3(signature = (l1_contract, slots, bypass_cache=false, immutable=false))]
+    fn read_words<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slots: Vec<Word>,
+        bypass_cache: bool,
+        immutable: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let slots: Vec<U256> = slots.into_iter().map(|slot| slot.0).collect();
+        let options = ReadOptions {
+            bypass_cache,
+            immutable,
+            ..Default::default()
+        };
+        self.spawn(py, |s| async move {
+            s.read_words(l1_contract.0, &slots, options)
+                .await
+                .map(words)
+        })
+    }
+
+    #[pyo3(signature = (l1_contract, slot, bypass_cache=false, immutable=false))]
+    fn read_word<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+        bypass_cache: bool,
+        immutable: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let options = ReadOptions {
+            bypass_cache,
+            immutable,
+            ..Default::default()
+        };
+        self.spawn(py, |s| async move {
+            s.read_word(l1_contract.0, slot.0, options)
+                .await
+                .map(|word| word.to_vec())
+        })
+    }
+
+    fn read_slot<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_slot(l1_contract.0, slot.0)
+                .await
+                .map(|bytes| bytes.to_vec())
+        })
+    }
+
+    fn read_multiple_slots<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slots: Vec<Word>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let slots: Vec<U256> = slots.into_iter().map(|slot| slot.0).collect();
+        self.spawn(py, |s| async move {
+            s.read_multiple_slots(l1_contract.0, slots)
+                .await
+                .map(|bytes| bytes.to_vec())
+        })
+    }
+
+    fn read_uint<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint(l1_contract.0, slot.0).await.map(Int)
+        })
+    }
+
+    fn read_uint160<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint160(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint128<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint128(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint96<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint96(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint64<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint64(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint48<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint48(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint32<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint32(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint24<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_uint24(l1_contract.0, slot.0)
+                .await
+                .map(|value| Int(U256::from(value)))
+        })
+    }
+
+    fn read_uint8<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(
+            py,
+            |s| async move { s.read_uint8(l1_contract.0, slot.0).await },
+        )
+    }
+
+    fn read_address<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(py, |s| async move {
+            s.read_address(l1_contract.0, slot.0)
+                .await
+                .map(|address| address.to_string())
+        })
+    }
+
+    fn read_string<'py>(
+        &self,
+        py: Python<'py>,
+        l1_contract: PyAddress,
+        slot: Word,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.spawn(
+            py,
+            |s| async move { s.read_string(l1_contract.0, slot.0).await },
+        )
+    }
+}
+
+/// Decodes the value of Solidity type `ty` stored `offset` bytes from the right of a
+/// 32-byte `word`, e.g. one returned by `Scroller.read_words`.
+#[pyfunction]
+#[pyo3(signature = (word, ty, offset=0))]
+fn decode_word(word: Word, ty: &str, offset: usize) -> PyResult<Value> {
+    let ty: ValueType = ty.parse().map_err(PyValueError::new_err)?;
+    storage::decode_word(word.0.into(), offset, ty)
+        .map(Value)
+        .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Slot of `mapping[key]` for a mapping declared at `base`.
+#[pyfunction]
+fn mapping_slot(key: Word, base: Word) -> Int {
+    Int(storage::mapping_slot(key.0.into(), base.0))
+}
+
+#[pyfunction]
+fn address_mapping_slot(key: PyAddress, base: Word) -> Int {
+    Int(storage::address_mapping_slot(key.0, base.0))
+}
+
+/// First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+#[pyfunction]
+fn data_slot(slot: Word) -> Int {
+    Int(storage::data_slot(slot.0))
+}
+
+#[pyfunction]
+fn erc20_balance_slot(account: PyAddress) -> Int {
+    Int(erc20::balance_slot(account.0))
+}
+
+#[pyfunction]
+fn erc20_allowance_slot(owner: PyAddress, spender: PyAddress) -> Int {
+    Int(erc20::allowance_slot(owner.0, spender.0))
+}
+
+#[pyfunction]
+fn erc721_owner_slot(token_id: Word) -> Int {
+    Int(erc721::owner_slot(token_id.0))
+}
+
+#[pyfunction]
+fn erc721_balance_slot(owner: PyAddress) -> Int {
+    Int(erc721::balance_slot(owner.0))
+}
+
+#[pyfunction]
+fn erc721_token_approval_slot(token_id: Word) -> Int {
+    Int(erc721::token_approval_slot(token_id.0))
+}
+
+#[pymodule]
+fn scroller(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Scroller>()?;
+    m.add("ScrollerError", m.py().get_type::<ScrollerError>())?;
+    m.add_function(wrap_pyfunction!(decode_word, m)?)?;
+    m.add_function(wrap_pyfunction!(mapping_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(address_mapping_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(data_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(erc20_balance_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(erc20_allowance_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(erc721_owner_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(erc721_balance_slot, m)?)?;
+    m.add_function(wrap_pyfunction!(erc721_token_approval_slot, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::types::PyDict;
+
+    use super::*;
+
+    #[test]
+    fn test_module() {
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "scroller").unwrap();
+            scroller(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("scroller", &module).unwrap();
+            py.run(
+                cr#"
+account = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC"
+assert scroller.erc20_balance_slot(account) == scroller.address_mapping_slot(account, 0)
+assert scroller.data_slot(0) == int(
+    "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563", 16
+)
+word = (5).to_bytes(12, "big") + bytes(19) + b"\xff"
+assert scroller.decode_word(word, "uint96", 20) == 5
+assert scroller.decode_word(word, "int8") == -1
+assert scroller.decode_word(word, "bool") is True
+try:
+    scroller.decode_word(word, "uint7")
+    raise AssertionError("uint7 accepted")
+except ValueError:
+    pass
+try:
+    scroller.decode_word(word, "uint256", 1)
+    raise AssertionError("offset past the slot accepted")
+except ValueError:
+    pass
+
+s = scroller.Scroller("http://127.0.0.1:1", cache_capacity=16)
+assert s.scroller_address is None
+assert s.cache_stats()["entries"] == 0
+try:
+    scroller.Scroller("http://127.0.0.1:1", cache_capacity=16, cache_path="cache.redb")
+    raise AssertionError("both caches accepted")
+except ValueError:
+    pass
+"#,
+                None,
+                Some(&locals),
+            )
+            .unwrap();
+        });
+    }
+}
@@ -0,0 +1,383 @@
+//! wasm-bindgen API over scroller-rs for browser dApps.
+//!
+//! ```bash
+//! wasm-pack build --target web
+//! ```
+//!
+//! ```js
+//! import init, { Scroller, erc20BalanceSlot, decodeWord } from "./pkg/scroller_wasm.js";
+//!
+//! await init();
+//! const scroller = new Scroller("https://l1sload-rpc.scroll.io", undefined, 1000);
+//! const [word] = await scroller.readWords(token, [erc20BalanceSlot(account)]);
+//! console.log(decodeWord(word, "uint256"));
+//! ```
+//!
+//! Slots are given as `BigInt`s, numbers or strings; integers come back as `BigInt`s and
+//! raw words as `Uint8Array`s. Reads return `Promise`s and go through `fetch`.
+
+use std::sync::Arc;
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    rpc::types::BlockId,
+};
+use js_sys::{Array, BigInt, Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+use scroller_rs::{
+    cache::{LruSlotCache, ReadOptions},
+    erc20, erc721,
+    l1scroller::{self, L1ScrollerContracttype},
+    layout::{MappingKey, StorageLayout},
+    precompile,
+    storage::{self, DecodedValue, ValueType},
+};
+
+type AsyncScroller = l1scroller::Scroller<L1ScrollerContracttype>;
+
+fn error(err: impl std::fmt::Display) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+fn to_u256(value: &JsValue) -> Result<U256, JsError> {
+    let text = if value.is_bigint() {
+        BigInt::unchecked_from_js_ref(value)
+            .to_string(10)
+            .map_err(|_| error("invalid BigInt"))?
+            .into()
+    } else if let Some(number) = value.as_f64() {
+        if number < 0.0 || number.fract() != 0.0 {
+            return Err(error(format!("invalid uint256 `{number}`")));
+        }
+        format!("{number:.0}")
+    } else {
+        value
+            .as_string()
+            .ok_or_else(|| error("expected a BigInt, number or string slot"))?
+    };
+    text.parse()
+        .map_err(|_| error(format!("invalid uint256 `{text}`")))
+}
+
+fn to_slots(slots: &[JsValue]) -> Result<Vec<U256>, JsError> {
+    slots.iter().map(to_u256).collect()
+}
+
+fn to_address(value: &str) -> Result<Address, JsError> {
+    value
+        .parse()
+        .map_err(|_| error(format!("invalid address `{value}`")))
+}
+
+fn to_bigint(value: impl std::fmt::Display) -> JsValue {
+    BigInt::new(&JsValue::from_str(&value.to_string()))
+        .expect("decimal integers parse as BigInt")
+        .into()
+}
+
+fn to_word(word: &[u8]) -> Result<B256, JsError> {
+    B256::try_from(word).map_err(|_| error(format!("expected 32 bytes, got {}", word.len())))
+}
+
+fn words(words: &[B256]) -> JsValue {
+    words
+        .iter()
+        .map(|word| JsValue::from(Uint8Array::from(word.as_slice())))
+        .collect::<Array>()
+        .into()
+}
+
+fn decoded(value: DecodedValue) -> JsValue {
+    match value {
+        DecodedValue::Uint(value) => to_bigint(value),
+        DecodedValue::Int(value) => to_bigint(value),
+        DecodedValue::Address(value) => value.to_string().into(),
+        DecodedValue::Bool(value) => value.into(),
+        DecodedValue::Bytes(value) => Uint8Array::from(value.as_ref()).into(),
+        DecodedValue::String(value) => value.into(),
+    }
+}
+
+/// Runs a read and resolves the returned promise with its result.
+fn spawn<F>(read: F) -> Promise
+where
+    F: std::future::Future<Output = Result<JsValue, JsError>> + 'static,
+{
+    future_to_promise(async move { read.await.map_err(JsValue::from) })
+}
+
+/// Reads L1 contract storage from Scroll.
+#[wasm_bindgen]
+pub struct Scroller {
+    inner: Arc<AsyncScroller>,
+}
+
+#[wasm_bindgen]
+impl Scroller {
+    /// Reads through the L1Scroller deployed at `scrollerAddress`, or calls the L1SLOAD
+    /// precompile directly when it is not given. `cacheCapacity` attaches an in-memory
+    /// slot cache to `readWords`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        rpc_url: &str,
+        scroller_address: Option<String>,
+        cache_capacity: Option<u32>,
+    ) -> Result<Scroller, JsError> {
+        let mut scroller = match scroller_address {
+            Some(address) => AsyncScroller::new(rpc_url, to_address(&address)?),
+            None => AsyncScroller::direct(rpc_url),
+        }
+        .map_err(error)?;
+        if let Some(capacity) = cache_capacity {
+            scroller = scroller.with_cache(Arc::new(LruSlotCache::new(capacity as usize)));
+        }
+        Ok(Self {
+            inner: Arc::new(scroller),
+        })
+    }
+
+    #[wasm_bindgen(getter, js_name = scrollerAddress)]
+    pub fn scroller_address(&self) -> Option<String> {
+        self.inner
+            .scroller_address()
+            .map(|address| address.to_string())
+    }
+
+    #[wasm_bindgen(js_name = l1BlockNumber)]
+    pub fn l1_block_number(&self) -> Promise {
+        let inner = self.inner.clone();
+        spawn(async move {
+            let block = inner.l1_block_number().await.map_err(error)?;
+            Ok(to_bigint(block))
+        })
+    }
+
+    #[wasm_bindgen(js_name = l2BlockNumber)]
+    pub fn l2_block_number(&self) -> Promise {
+        let inner = self.inner.clone();
+        spawn(async move {
+            let block = inner.l2_block_number().await.map_err(error)?;
+            Ok(to_bigint(block))
+        })
+    }
+
+    /// Raw words of any number of slots through the L1SLOAD precompile, batched five per
+    /// call and served from the cache when one is attached.
+    #[wasm_bindgen(js_name = readWords)]
+    pub fn read_words(&self, l1_contract: &str, slots: Vec<JsValue>) -> Result<Promise, JsError> {
+        let inner = self.inner.clone();
+        let l1_contract = to_address(l1_contract)?;
+        let slots = to_slots(&slots)?;
+        Ok(spawn(async move {
+            let values = inner
+                .read_words(l1_contract, &slots, ReadOptions::default())
+                .await
+                .map_err(error)?;
+            Ok(words(&values))
+        }))
+    }
+
+    #[wasm_bindgen(js_name = readUint)]
+    pub fn read_uint(&self, l1_contract: &str, slot: JsValue) -> Result<Promise, JsError> {
+        let inner = self.inner.clone();
+        let l1_contract = to_address(l1_contract)?;
+        let slot = to_u256(&slot)?;
+        Ok(spawn(async move {
+            let value = inner.read_uint(l1_contract, slot).await.map_err(error)?;
+            Ok(to_bigint(value))
+        }))
+    }
+
+    #[wasm_bindgen(js_name = readAddress)]
+    pub fn read_address(&self, l1_contract: &str, slot: JsValue) -> Result<Promise, JsError> {
+        let inner = self.inner.clone();
+        let l1_contract = to_address(l1_contract)?;
+        let slot = to_u256(&slot)?;
+        Ok(spawn(async move {
+            let address = inner.read_address(l1_contract, slot).await.map_err(error)?;
+            Ok(address.to_string().into())
+        }))
+    }
+
+    #[wasm_bindgen(js_name = readString)]
+    pub fn read_string(&self, l1_contract: &str, slot: JsValue) -> Result<Promise, JsError> {
+        let inner = self.inner.clone();
+        let l1_contract = to_address(l1_contract)?;
+        let slot = to_u256(&slot)?;
+        Ok(spawn(async move {
+            let value = inner.read_string(l1_contract, slot).await.map_err(error)?;
+            Ok(value.into())
+        }))
+    }
+
+    /// Reads and decodes every variable of a solc storage layout (or a forge artifact
+    /// containing one), expanding mappings with `keys` such as `balances=0xA8E5...`.
+    /// Resolves to `[{ name, type, slot, offset, value }]`.
+    #[wasm_bindgen(js_name = readLayout)]
+    pub fn read_layout(
+        &self,
+        l1_contract: &str,
+        layout: &str,
+        keys: Vec<String>,
+    ) -> Result<Promise, JsError> {
+        let inner = self.inner.clone();
+        let l1_contract = to_address(l1_contract)?;
+        let layout = StorageLayout::from_json(layout).map_err(error)?;
+        let keys = keys
+            .iter()
+            .map(|key| key.parse::<MappingKey>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(error)?;
+        Ok(spawn(async move {
+            let values = inner
+                .read_layout(l1_contract, &layout, &keys, BlockId::latest())
+                .await
+                .map_err(error)?;
+            serde_wasm_bindgen::to_value(&values).map_err(error)
+        }))
+    }
+}
+
+/// Decodes the value of Solidity type `ty` stored `offset` bytes from the right of a
+/// 32-byte `word`, e.g. one returned by `Scroller.readWords`.
+#[wasm_bindgen(js_name = decodeWord)]
+pub fn decode_word(word: &[u8], ty: &str, offset: Option<u32>) -> Result<JsValue, JsError> {
+    let ty: ValueType = ty.parse().map_err(error)?;
+    let value =
+        storage::decode_word(to_word(word)?, offset.unwrap_or(0) as usize, ty).map_err(error)?;
+    Ok(decoded(value))
+}
+
+/// Splits the output of an L1SLOAD call reading `slots` slots into one word per slot.
+#[wasm_bindgen(js_name = decodeL1sloadOutput)]
+pub fn decode_l1sload_output(output: &[u8], slots: usize) -> Result<JsValue, JsError> {
+    let values =
+        precompile::decode_l1sload_output(&output.to_vec().into(), slots).map_err(error)?;
+    Ok(words(&values))
+}
+
+/// Calldata of an L1SLOAD call reading `slots` of `l1Contract`.
+#[wasm_bindgen(js_name = encodeL1sloadInput)]
+pub fn encode_l1sload_input(l1_contract: &str, slots: Vec<JsValue>) -> Result<Vec<u8>, JsError> {
+    let input = precompile::encode_l1sload_input(to_address(l1_contract)?, &to_slots(&slots)?)
+        .map_err(error)?;
+    Ok(input.to_vec())
+}
+
+/// Slot of `mapping[key]` for a mapping declared at `base`.
+#[wasm_bindgen(js_name = mappingSlot)]
+pub fn mapping_slot(key: JsValue, base: JsValue) -> Result<JsValue, JsError> {
+    Ok(to_bigint(storage::mapping_slot(
+        to_u256(&key)?.into(),
+        to_u256(&base)?,
+    )))
+}
+
+#[wasm_bindgen(js_name = addressMappingSlot)]
+pub fn address_mapping_slot(key: &str, base: JsValue) -> Result<JsValue, JsError> {
+    Ok(to_bigint(storage::address_mapping_slot(
+        to_address(key)?,
+        to_u256(&base)?,
+    )))
+}
+
+/// First slot holding the data of a dynamic array, `bytes` or `string` declared at `slot`.
+#[wasm_bindgen(js_name = dataSlot)]
+pub fn data_slot(slot: JsValue) -> Result<JsValue, JsError> {
+    Ok(to_bigint(storage::data_slot(to_u256(&slot)?)))
+}
+
+#[wasm_bindgen(js_name = erc20BalanceSlot)]
+pub fn erc20_balance_slot(account: &str) -> Result<JsValue, JsError> {
+    Ok(to_bigint(erc20::balance_slot(to_address(account)?)))
+}
+
+#[wasm_bindgen(js_name = erc20AllowanceSlot)]
+pub fn erc20_allowance_slot(owner: &str, spender: &str) -> Result<JsValue, JsError> {
+    Ok(to_bigint(erc20::allowance_slot(
+        to_address(owner)?,
+        to_address(spender)?,
+    )))
+}
+
+#[wasm_bindgen(js_name = erc721OwnerSlot)]
+pub fn erc721_owner_slot(token_id: JsValue) -> Result<JsValue, JsError> {
+    Ok(to_bigint(erc721::owner_slot(to_u256(&token_id)?)))
+}
+
+#[wasm_bindgen(js_name = erc721BalanceSlot)]
+pub fn erc721_balance_slot(owner: &str) -> Result<JsValue, JsError> {
+    Ok(to_bigint(erc721::balance_slot(to_address(owner)?)))
+}
+
+#[wasm_bindgen(js_name = erc721TokenApprovalSlot)]
+pub fn erc721_token_approval_slot(token_id: JsValue) -> Result<JsValue, JsError> {
+    Ok(to_bigint(erc721::token_approval_slot(to_u256(&token_id)?)))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_futures::JsFuture;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+
+    const TOKEN: &str = "0xA8E50c2607678747D9d8A24AC52234712bE41fD9";
+    const ACCOUNT: &str = "0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC";
+
+    fn bigint(value: &str) -> JsValue {
+        BigInt::new(&JsValue::from_str(value)).unwrap().into()
+    }
+
+    #[wasm_bindgen_test]
+    fn test_slots() {
+        assert_eq!(
+            erc20_balance_slot(ACCOUNT).ok(),
+            address_mapping_slot(ACCOUNT, JsValue::from(0)).ok()
+        );
+        assert_eq!(
+            data_slot(JsValue::from_str("0x0")).ok(),
+            Some(bigint(
+                "0x290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563"
+            ))
+        );
+        assert!(data_slot(JsValue::from(-1)).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_decode() {
+        let mut word = [0u8; 32];
+        word[11] = 5;
+        word[31] = 0xff;
+        assert_eq!(
+            decode_word(&word, "uint96", Some(20)).ok(),
+            Some(bigint("5"))
+        );
+        assert_eq!(decode_word(&word, "int8", None).ok(), Some(bigint("-1")));
+        assert_eq!(decode_word(&word, "bool", None).ok(), Some(JsValue::TRUE));
+        assert!(decode_word(&word, "uint96", Some(21)).is_err());
+
+        let input = encode_l1sload_input(TOKEN, vec![bigint("1"), JsValue::from(2)]).ok();
+        assert_eq!(input.map(|input| input.len()), Some(20 + 64));
+        let words: Array = decode_l1sload_output(&[word, word].concat(), 2)
+            .ok()
+            .unwrap()
+            .into();
+        assert_eq!(Uint8Array::from(words.get(1)).to_vec(), word);
+        assert!(decode_l1sload_output(&word, 2).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    async fn test_failed_read_rejects() {
+        let scroller = Scroller::new("http://127.0.0.1:1", None, Some(16))
+            .ok()
+            .unwrap();
+        let read = scroller
+            .read_words(TOKEN, vec![JsValue::from(0)])
+            .ok()
+            .unwrap();
+        assert!(JsFuture::from(read).await.is_err());
+    }
+}
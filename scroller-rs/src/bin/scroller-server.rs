@@ -0,0 +1,111 @@
+//! `scroller-server`: HTTP/JSON gateway serving cached L1SLOAD reads.
+//!
+//! ```bash
+//! scroller-server --rpc <RPC_URL> --listen 127.0.0.1:8080 --cache-path slots.redb
+//! curl 'http://127.0.0.1:8080/v1/0xA8E5...1fD9/slot/0?type=uint96&offset=20'
+//! curl http://127.0.0.1:8080/rpc -H 'content-type: application/json' \
+//!     -d '{"jsonrpc":"2.0","id":1,"method":"l1scroller_readSlot","params":["0xA8E5...1fD9","0x0"]}'
+//! scroller-server --mock snapshot.json
+//! RUST_LOG=scroller_rs=debug scroller-server   # log every RPC call
+//! ```
+
+use std::{net::SocketAddr, path::PathBuf, sync::Arc};
+
+use clap::Parser;
+use scroller_rs::{
+    cache::{LruSlotCache, SlotCache, DEFAULT_CACHE_CAPACITY},
+    disk_cache::DiskSlotCache,
+    l1scroller::Scroller,
+    server::{self, SnapshotSource},
+    snapshot::Snapshot,
+};
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_RPC_URL: &str = "https://l1sload-rpc.scroll.io";
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "scroller-server",
+    version,
+    about = "Serve L1SLOAD reads over HTTP/JSON"
+)]
+struct Cli {
+    /// Scroll RPC endpoint.
+    #[arg(long, env = "SCROLLER_RPC_URL", default_value = DEFAULT_RPC_URL)]
+    rpc: String,
+
+    /// Address to listen on.
+    #[arg(long, env = "SCROLLER_LISTEN", default_value = "127.0.0.1:8080")]
+    listen: SocketAddr,
+
+    /// Entries kept in the in-memory slot cache [default: 10000].
+    #[arg(long, conflicts_with = "cache_path")]
+    cache_capacity: Option<usize>,
+
+    /// Persist the slot cache in this redb file instead of memory.
+    #[arg(long)]
+    cache_path: Option<PathBuf>,
+
+    /// Serve these `scroller snapshot` files instead of reading from `--rpc`.
+    #[arg(long, num_args = 1.., conflicts_with_all = ["cache_capacity", "cache_path"])]
+    mock: Vec<PathBuf>,
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+    let cli = Cli::parse();
+
+    let router = if cli.mock.is_empty() {
+        let cache: Arc<dyn SlotCache> = match &cli.cache_path {
+            Some(path) => Arc::new(DiskSlotCache::open(path)?),
+            None => Arc::new(LruSlotCache::new(
+                cli.cache_capacity.unwrap_or(DEFAULT_CACHE_CAPACITY),
+            )),
+        };
+        server::router(Arc::new(Scroller::direct(&cli.rpc)?.with_cache(cache)))
+    } else {
+        let snapshots = cli
+            .mock
+            .iter()
+            .map(Snapshot::from_file)
+            .collect::<eyre::Result<Vec<_>>>()?;
+        server::router(Arc::new(SnapshotSource::new(snapshots)))
+    };
+    #[cfg(feature = "metrics")]
+    let router = router.merge(server::metrics_router(
+        scroller_rs::telemetry::install_prometheus()?,
+    ));
+
+    let listener = tokio::net::TcpListener::bind(cli.listen).await?;
+    tracing::info!("listening on http://{}", listener.local_addr()?);
+    server::serve(listener, router).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+        let cli = Cli::try_parse_from(["scroller-server", "--mock", "a.json", "b.json"]).unwrap();
+        assert_eq!(cli.mock.len(), 2);
+        assert!(Cli::try_parse_from(["scroller-server", "--listen", "nope"]).is_err());
+        assert!(Cli::try_parse_from([
+            "scroller-server",
+            "--cache-capacity",
+            "10",
+            "--cache-path",
+            "slots.redb",
+        ])
+        .is_err());
+    }
+}
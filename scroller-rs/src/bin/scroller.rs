@@ -0,0 +1,746 @@
+//! `scroller` command line tool for ad-hoc L1SLOAD reads.
+//!
+//! ```bash
+//! scroller read-slot 0xA8E5...1fD9 0
+//! scroller read 0xA8E5...1fD9 2 --type uint96 --block 123456 --json
+//! scroller erc20 balance <token> <account>
+//! scroller layout dump --layout Vault.json --address <contract> --key balances=<account>
+//! scroller check --l1-rpc <L1_RPC_URL> 0xA8E5...1fD9 0 1 2
+//! scroller diff --address <contract> --slot 0 --typed 1=uint96@20 --from 100 --format csv
+//! ```
+
+use std::{path::PathBuf, process::ExitCode, str::FromStr};
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    providers::ProviderBuilder,
+    rpc::types::BlockId,
+    transports::http::reqwest::Url,
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use scroller_rs::{
+    erc20, erc721,
+    l1scroller::{L1ScrollerContracttype, Scroller},
+    layout::{self, LayoutVariable, MappingKey, StorageLayout},
+    precompile::{decode_l1sload_output, L1SLOAD_MAX_SLOTS},
+    snapshot::Snapshot,
+    storage::{decode_word, DecodedValue, StringSlot, ValueType},
+};
+use serde_json::json;
+use tracing_subscriber::EnvFilter;
+
+const DEFAULT_RPC_URL: &str = "https://l1sload-rpc.scroll.io";
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "scroller",
+    version,
+    about = "Read L1 contract storage from Scroll through L1SLOAD"
+)]
+struct Cli {
+    #[command(flatten)]
+    options: Options,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Args)]
+struct Options {
+    /// Scroll RPC endpoint.
+    #[arg(long, global = true, env = "SCROLLER_RPC_URL", default_value = DEFAULT_RPC_URL)]
+    rpc: String,
+
+    /// Read through this L1Scroller deployment instead of calling the L1SLOAD precompile
+    /// directly. `check`, `snapshot` and `diff` always call the precompile.
+    #[arg(long, global = true)]
+    scroller: Option<Address>,
+
+    /// L2 block to read at, decimal or 0x-prefixed. Defaults to the latest block.
+    #[arg(long, global = true, value_parser = parse_block)]
+    block: Option<u64>,
+
+    /// Print JSON, including the L2 and L1 blocks the values were read at.
+    #[arg(long, global = true)]
+    json: bool,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    #[command(flatten)]
+    Read(ReadCommand),
+    /// Storage described by a forge `storageLayout`.
+    #[command(subcommand)]
+    Layout(LayoutCommand),
+    /// Compare slots read through L1SLOAD with `eth_getStorageAt` on L1, at the L1 block
+    /// visible through L1Blocks. Exits with status 1 on a mismatch.
+    Check {
+        /// Ethereum RPC endpoint to read L1 state from.
+        #[arg(long, env = "SCROLLER_L1_RPC_URL")]
+        l1_rpc: String,
+        contract: Address,
+        #[arg(required = true)]
+        slots: Vec<U256>,
+    },
+    /// Save the raw words of a set of slots at `--block` as JSON.
+    Snapshot {
+        #[command(flatten)]
+        slots: SnapshotArgs,
+        /// Write the snapshot to a file instead of stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Compare a set of slots between two L2 blocks.
+    Diff {
+        #[command(flatten)]
+        slots: SnapshotArgs,
+        /// L2 block to compare from, decimal or 0x-prefixed.
+        #[arg(long, value_parser = parse_block)]
+        from: u64,
+        /// L2 block to compare to. Defaults to `--block`, or the latest block.
+        #[arg(long, value_parser = parse_block)]
+        to: Option<u64>,
+        #[arg(long, value_enum, default_value_t = DiffFormat::Json)]
+        format: DiffFormat,
+    },
+}
+
+/// Commands reading and decoding a fixed list of slots, see [`ReadCommand::items`].
+#[derive(Debug, Subcommand)]
+enum ReadCommand {
+    /// Read the raw word stored in a slot.
+    ReadSlot { contract: Address, slot: U256 },
+    /// Read the raw words stored in several slots.
+    ReadSlots {
+        contract: Address,
+        #[arg(required = true)]
+        slots: Vec<U256>,
+    },
+    /// Read a slot and decode it as a Solidity value type.
+    Read {
+        contract: Address,
+        slot: U256,
+        /// `uint<N>`, `int<N>`, `address`, `bool`, `bytes<N>` or `string`.
+        #[arg(long = "type", default_value = "uint256")]
+        ty: ValueType,
+        /// Byte offset of a value packed with others in the slot.
+        #[arg(long, default_value_t = 0)]
+        offset: usize,
+    },
+    /// Read a `string` stored at a slot, short or long.
+    ReadString { contract: Address, slot: U256 },
+    /// OpenZeppelin ERC20 storage.
+    #[command(subcommand)]
+    Erc20(Erc20Command),
+    /// OpenZeppelin ERC721 storage.
+    #[command(subcommand)]
+    Erc721(Erc721Command),
+}
+
+#[derive(Debug, Subcommand)]
+enum Erc20Command {
+    Balance {
+        token: Address,
+        account: Address,
+    },
+    Allowance {
+        token: Address,
+        owner: Address,
+        spender: Address,
+    },
+    TotalSupply {
+        token: Address,
+    },
+    Name {
+        token: Address,
+    },
+    Symbol {
+        token: Address,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum Erc721Command {
+    Owner { token: Address, token_id: U256 },
+    Balance { token: Address, owner: Address },
+    Approved { token: Address, token_id: U256 },
+}
+
+#[derive(Debug, Subcommand)]
+enum LayoutCommand {
+    /// Read and decode every top-level variable of a contract.
+    Dump(DumpArgs),
+}
+
+#[derive(Debug, Args)]
+struct DumpArgs {
+    /// `forge inspect <Contract> storageLayout --json` output, or a forge artifact
+    /// containing `storageLayout`.
+    #[arg(long)]
+    layout: PathBuf,
+
+    /// L1 contract to read.
+    #[arg(long)]
+    address: Address,
+
+    /// Mapping keys or array indices to expand, as `<variable>=<key>[,<key>...]`.
+    /// Can be repeated.
+    #[arg(long = "key")]
+    keys: Vec<MappingKey>,
+}
+
+#[derive(Debug, Args)]
+struct SnapshotArgs {
+    /// L1 contract to read.
+    #[arg(long)]
+    address: Address,
+
+    /// Slot to include. Can be repeated.
+    #[arg(long = "slot")]
+    slots: Vec<U256>,
+
+    /// Slot to include and decode in diffs, as `<slot>=<type>` with the types of
+    /// `read --type`, or `<slot>=<type>@<offset>` for a packed value. Can be repeated.
+    #[arg(long, value_parser = parse_typed)]
+    typed: Vec<LayoutVariable>,
+
+    /// Also include every variable of this forge `storageLayout`, decoded in diffs.
+    #[arg(long, required_unless_present_any = ["slots", "typed"])]
+    layout: Option<PathBuf>,
+
+    /// Mapping keys or array indices to expand with `--layout`, as
+    /// `<variable>=<key>[,<key>...]`.
+    #[arg(long = "key", requires = "layout")]
+    keys: Vec<MappingKey>,
+}
+
+impl SnapshotArgs {
+    fn variables(&self) -> eyre::Result<Vec<LayoutVariable>> {
+        let mut variables = match &self.layout {
+            Some(path) => StorageLayout::from_file(path)?.variables(&self.keys)?,
+            None => Vec::new(),
+        };
+        variables.extend(self.typed.iter().cloned());
+        Ok(variables)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum DiffFormat {
+    Json,
+    Csv,
+}
+
+fn parse_typed(s: &str) -> Result<LayoutVariable, String> {
+    let (slot, ty) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected `<slot>=<type>[@<offset>]`, got `{s}`"))?;
+    let (ty, offset) = match ty.split_once('@') {
+        Some((ty, offset)) => (
+            ty,
+            offset
+                .parse()
+                .map_err(|_| format!("invalid offset `{offset}`"))?,
+        ),
+        None => (ty, 0),
+    };
+    let slot = U256::from_str(slot).map_err(|_| format!("invalid slot `{slot}`"))?;
+    Ok(LayoutVariable::typed(slot, offset, ty.parse()?))
+}
+
+fn parse_block(s: &str) -> Result<u64, String> {
+    match s.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => s.parse(),
+    }
+    .map_err(|err| format!("invalid block `{s}`: {err}"))
+}
+
+/// One value to read: `ty` is `None` for the raw word.
+struct Item {
+    name: String,
+    slot: U256,
+    ty: Option<ValueType>,
+    offset: usize,
+}
+
+impl Item {
+    fn raw(slot: U256) -> Self {
+        Self {
+            name: format!("{slot:#x}"),
+            slot,
+            ty: None,
+            offset: 0,
+        }
+    }
+
+    fn typed(name: &str, slot: U256, ty: ValueType) -> Self {
+        Self {
+            name: name.to_string(),
+            slot,
+            ty: Some(ty),
+            offset: 0,
+        }
+    }
+}
+
+impl ReadCommand {
+    fn items(self) -> (Address, Vec<Item>) {
+        match self {
+            ReadCommand::ReadSlot { contract, slot } => (contract, vec![Item::raw(slot)]),
+            ReadCommand::ReadSlots { contract, slots } => {
+                (contract, slots.into_iter().map(Item::raw).collect())
+            }
+            ReadCommand::Read {
+                contract,
+                slot,
+                ty,
+                offset,
+            } => (
+                contract,
+                vec![Item {
+                    offset,
+                    ..Item::typed(&format!("{slot:#x}"), slot, ty)
+                }],
+            ),
+            ReadCommand::ReadString { contract, slot } => (
+                contract,
+                vec![Item::typed(&format!("{slot:#x}"), slot, ValueType::String)],
+            ),
+            ReadCommand::Erc20(command) => match command {
+                Erc20Command::Balance { token, account } => (
+                    token,
+                    vec![Item::typed(
+                        "balance",
+                        erc20::balance_slot(account),
+                        ValueType::Uint(256),
+                    )],
+                ),
+                Erc20Command::Allowance {
+                    token,
+                    owner,
+                    spender,
+                } => (
+                    token,
+                    vec![Item::typed(
+                        "allowance",
+                        erc20::allowance_slot(owner, spender),
+                        ValueType::Uint(256),
+                    )],
+                ),
+                Erc20Command::TotalSupply { token } => (
+                    token,
+                    vec![Item::typed(
+                        "totalSupply",
+                        erc20::TOTAL_SUPPLY_SLOT,
+                        ValueType::Uint(256),
+                    )],
+                ),
+                Erc20Command::Name { token } => (
+                    token,
+                    vec![Item::typed("name", erc20::NAME_SLOT, ValueType::String)],
+                ),
+                Erc20Command::Symbol { token } => (
+                    token,
+                    vec![Item::typed("symbol", erc20::SYMBOL_SLOT, ValueType::String)],
+                ),
+            },
+            ReadCommand::Erc721(command) => match command {
+                Erc721Command::Owner { token, token_id } => (
+                    token,
+                    vec![Item::typed(
+                        "owner",
+                        erc721::owner_slot(token_id),
+                        ValueType::Address,
+                    )],
+                ),
+                Erc721Command::Balance { token, owner } => (
+                    token,
+                    vec![Item::typed(
+                        "balance",
+                        erc721::balance_slot(owner),
+                        ValueType::Uint(256),
+                    )],
+                ),
+                Erc721Command::Approved { token, token_id } => (
+                    token,
+                    vec![Item::typed(
+                        "approved",
+                        erc721::token_approval_slot(token_id),
+                        ValueType::Address,
+                    )],
+                ),
+            },
+        }
+    }
+}
+
+struct Reader {
+    scroller: Scroller<L1ScrollerContracttype>,
+    through_scroller: bool,
+    block: BlockId,
+}
+
+impl Reader {
+    async fn words(&self, contract: Address, slots: &[U256]) -> eyre::Result<Vec<B256>> {
+        self.words_at(contract, slots, self.block).await
+    }
+
+    async fn words_at(
+        &self,
+        contract: Address,
+        slots: &[U256],
+        block: BlockId,
+    ) -> eyre::Result<Vec<B256>> {
+        if !self.through_scroller {
+            return Ok(self.scroller.read_words_at(contract, slots, block).await?);
+        }
+        let mut words = Vec::with_capacity(slots.len());
+        for chunk in slots.chunks(L1SLOAD_MAX_SLOTS) {
+            let output = self
+                .scroller
+                .read_multiple_slots_at(contract, chunk.to_vec(), block)
+                .await?;
+            words.extend(decode_l1sload_output(&output, chunk.len())?);
+        }
+        Ok(words)
+    }
+
+    async fn decode(
+        &self,
+        contract: Address,
+        item: &Item,
+        word: B256,
+    ) -> eyre::Result<DecodedValue> {
+        let Some(ty) = item.ty else {
+            return Ok(DecodedValue::Bytes(word.into()));
+        };
+        if ty != ValueType::String {
+            return Ok(decode_word(word, item.offset, ty)?);
+        }
+        match StringSlot::decode(word)? {
+            string @ StringSlot::Long { len } => {
+                let data = self.words(contract, &string.data_slots(item.slot)?).await?;
+                let bytes = data.concat();
+                Ok(DecodedValue::String(
+                    String::from_utf8_lossy(&bytes[..len.min(bytes.len())]).into_owned(),
+                ))
+            }
+            StringSlot::Short(_) => Ok(decode_word(word, item.offset, ty)?),
+        }
+    }
+
+    /// Reads `args.slots` and the slots of the `variables` from `args.layout` at `l2_block`.
+    async fn snapshot(
+        &self,
+        args: &SnapshotArgs,
+        variables: &[LayoutVariable],
+        l2_block: u64,
+    ) -> eyre::Result<Snapshot> {
+        Ok(self
+            .scroller
+            .snapshot(
+                args.address,
+                &args.slots,
+                variables,
+                BlockId::number(l2_block),
+            )
+            .await?)
+    }
+}
+
+#[tokio::main]
+async fn main() -> eyre::Result<ExitCode> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+    let Cli { options, command } = Cli::parse();
+    let scroller = match options.scroller {
+        Some(address) => Scroller::new(&options.rpc, address)?,
+        None => Scroller::direct(&options.rpc)?,
+    };
+    let l2_block = match options.block {
+        Some(block) => block,
+        None => scroller.l2_block_number().await?,
+    };
+    let reader = Reader {
+        scroller,
+        through_scroller: options.scroller.is_some(),
+        block: BlockId::number(l2_block),
+    };
+
+    let command = match command {
+        Command::Read(command) => command,
+        Command::Layout(LayoutCommand::Dump(args)) => {
+            dump_layout(&reader, options.json, l2_block, args).await?;
+            return Ok(ExitCode::SUCCESS);
+        }
+        Command::Check {
+            l1_rpc,
+            contract,
+            slots,
+        } => return check(&reader, options.json, l2_block, &l1_rpc, contract, &slots).await,
+        Command::Snapshot { slots, out } => {
+            let snapshot = reader
+                .snapshot(&slots, &slots.variables()?, l2_block)
+                .await?;
+            match out {
+                Some(path) => snapshot.to_file(path)?,
+                None => println!("{}", snapshot.to_json()?),
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+        Command::Diff {
+            slots,
+            from,
+            to,
+            format,
+        } => {
+            let variables = slots.variables()?;
+            let old = reader.snapshot(&slots, &variables, from).await?;
+            let new = reader
+                .snapshot(&slots, &variables, to.unwrap_or(l2_block))
+                .await?;
+            let diff = old.diff_with_layout(&new, &variables)?;
+            match format {
+                DiffFormat::Json => println!("{}", diff.to_json()?),
+                DiffFormat::Csv => print!("{}", diff.to_csv()),
+            }
+            return Ok(ExitCode::SUCCESS);
+        }
+    };
+    let (contract, items) = command.items();
+    let slots: Vec<U256> = items.iter().map(|item| item.slot).collect();
+    let words = reader.words(contract, &slots).await?;
+    let mut values = Vec::with_capacity(items.len());
+    for (item, word) in items.iter().zip(&words) {
+        values.push(reader.decode(contract, item, *word).await?);
+    }
+
+    if options.json {
+        let l1_block = reader.scroller.l1_block_number_at(reader.block).await?;
+        let values: Vec<_> = items
+            .iter()
+            .zip(&words)
+            .zip(&values)
+            .map(|((item, word), value)| {
+                json!({
+                    "name": item.name,
+                    "slot": format!("{:#x}", item.slot),
+                    "type": item.ty.map(|ty| ty.to_string()),
+                    "raw": word,
+                    "value": value,
+                })
+            })
+            .collect();
+        let output = json!({
+            "l1Contract": contract,
+            "l2Block": l2_block,
+            "l1Block": l1_block,
+            "values": values,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+    } else if let [value] = values.as_slice() {
+        println!("{value}");
+    } else {
+        for (item, value) in items.iter().zip(&values) {
+            println!("{}: {value}", item.name);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn check(
+    reader: &Reader,
+    json: bool,
+    l2_block: u64,
+    l1_rpc: &str,
+    contract: Address,
+    slots: &[U256],
+) -> eyre::Result<ExitCode> {
+    let l1_provider = ProviderBuilder::new().on_http(Url::from_str(l1_rpc)?);
+    let report = reader
+        .scroller
+        .check_consistency_at(&l1_provider, contract, slots, l2_block)
+        .await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!(
+            "L2 block {} sees L1 block {}",
+            report.l2_block, report.l1_block
+        );
+        for check in &report.checks {
+            match check.matches() {
+                true => println!("{:#x}: ok", check.slot),
+                false => println!(
+                    "{:#x}: MISMATCH l2={} l1={}",
+                    check.slot, check.l2, check.l1
+                ),
+            }
+        }
+    }
+    Ok(match report.is_consistent() {
+        true => ExitCode::SUCCESS,
+        false => ExitCode::FAILURE,
+    })
+}
+
+async fn dump_layout(
+    reader: &Reader,
+    json: bool,
+    l2_block: u64,
+    args: DumpArgs,
+) -> eyre::Result<()> {
+    let layout = StorageLayout::from_file(&args.layout)?;
+    let variables = layout.variables(&args.keys)?;
+    let words = layout::collect_words(&variables, async |slots: Vec<U256>| {
+        reader.words(args.address, &slots).await
+    })
+    .await?;
+    let values = layout::decode(&variables, &words)?;
+
+    if json {
+        let l1_block = reader.scroller.l1_block_number_at(reader.block).await?;
+        let output = json!({
+            "l1Contract": args.address,
+            "l2Block": l2_block,
+            "l1Block": l1_block,
+            "values": values,
+        });
+        println!("{}", serde_json::to_string_pretty(&output)?);
+        return Ok(());
+    }
+
+    let rows: Vec<[String; 5]> = values
+        .iter()
+        .map(|value| {
+            [
+                value.name.clone(),
+                value.type_label.clone(),
+                format!("{:#x}", value.slot),
+                value.offset.to_string(),
+                value.value.to_string(),
+            ]
+        })
+        .collect();
+    let header = ["NAME", "TYPE", "SLOT", "OFFSET", "VALUE"].map(String::from);
+    let mut widths = header.clone().map(|column| column.len());
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.len());
+        }
+    }
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(column, width)| format!("{column:width$}"))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory;
+
+    use super::*;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from([
+            "scroller",
+            "read",
+            "0xA8E50c2607678747D9d8A24AC52234712bE41fD9",
+            "2",
+            "--type",
+            "uint96",
+            "--block",
+            "0x10",
+            "--json",
+        ])
+        .unwrap();
+        assert_eq!(cli.options.block, Some(16));
+        assert!(cli.options.json);
+        let Command::Read(command) = cli.command else {
+            panic!("expected read");
+        };
+        let (_, items) = command.items();
+        assert_eq!(items[0].ty, Some(ValueType::Uint(96)));
+
+        assert!(Cli::try_parse_from(["scroller", "read-slots", "0x00"]).is_err());
+
+        let cli = Cli::try_parse_from([
+            "scroller",
+            "layout",
+            "dump",
+            "--layout",
+            "Vault.json",
+            "--address",
+            "0xA8E50c2607678747D9d8A24AC52234712bE41fD9",
+            "--key",
+            "allowances=0xA8E50c2607678747D9d8A24AC52234712bE41fD9,0xfA75fa50f36bb87669d0D4B8382BeC1C1C9570eC",
+        ])
+        .unwrap();
+        let Command::Layout(LayoutCommand::Dump(args)) = cli.command else {
+            panic!("expected layout dump");
+        };
+        assert_eq!(args.keys[0].variable, "allowances");
+        assert_eq!(args.keys[0].keys.len(), 2);
+
+        let cli = Cli::try_parse_from([
+            "scroller",
+            "diff",
+            "--address",
+            "0xA8E50c2607678747D9d8A24AC52234712bE41fD9",
+            "--slot",
+            "0",
+            "--slot",
+            "1",
+            "--typed",
+            "0x2=uint96@20",
+            "--from",
+            "100",
+            "--format",
+            "csv",
+        ])
+        .unwrap();
+        let Command::Diff {
+            slots, to, format, ..
+        } = cli.command
+        else {
+            panic!("expected diff");
+        };
+        assert_eq!((slots.slots.len(), to, format), (2, None, DiffFormat::Csv));
+        let typed = &slots.variables().unwrap()[0];
+        assert_eq!((typed.slot, typed.offset), (U256::from(2), 20));
+        assert_eq!(typed.type_label, "uint96");
+        assert!(parse_typed("2=uint7").is_err());
+
+        let cli = Cli::try_parse_from([
+            "scroller",
+            "check",
+            "--l1-rpc",
+            "http://localhost:8545",
+            "0xA8E50c2607678747D9d8A24AC52234712bE41fD9",
+            "0",
+            "1",
+        ])
+        .unwrap();
+        assert!(matches!(cli.command, Command::Check { slots, .. } if slots.len() == 2));
+
+        assert!(Cli::try_parse_from([
+            "scroller",
+            "snapshot",
+            "--address",
+            "0xA8E50c2607678747D9d8A24AC52234712bE41fD9",
+        ])
+        .is_err());
+    }
+}
@@ -1,1093 +0,0 @@
-/**
-
-Generated by the following Solidity interface...
-```solidity
-interface ERC20L1Scroller {
-    function allowances_slot(address account, address spender) external pure returns (uint256);
-    function balances_slot(address account) external pure returns (uint256);
-    function name_slot() external pure returns (uint256);
-    function symbol_slot() external pure returns (uint256);
-    function total_supply_slot() external pure returns (uint256);
-}
-```
-
-...which was generated by the following JSON ABI:
-```json
-[
-  {
-    "type": "function",
-    "name": "allowances_slot",
-    "inputs": [
-      {
-        "name": "account",
-        "type": "address",
-        "internalType": "address"
-      },
-      {
-        "name": "spender",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "pure"
-  },
-  {
-    "type": "function",
-    "name": "balances_slot",
-    "inputs": [
-      {
-        "name": "account",
-        "type": "address",
-        "internalType": "address"
-      }
-    ],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "pure"
-  },
-  {
-    "type": "function",
-    "name": "name_slot",
-    "inputs": [],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "pure"
-  },
-  {
-    "type": "function",
-    "name": "symbol_slot",
-    "inputs": [],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "pure"
-  },
-  {
-    "type": "function",
-    "name": "total_supply_slot",
-    "inputs": [],
-    "outputs": [
-      {
-        "name": "",
-        "type": "uint256",
-        "internalType": "uint256"
-      }
-    ],
-    "stateMutability": "pure"
-  }
-]
-```*/
-#[allow(
-    non_camel_case_types,
-    non_snake_case,
-    clippy::pub_underscore_fields,
-    clippy::style
-)]
-pub mod ERC20L1Scroller {
-    use super::*;
-    use alloy::sol_types as alloy_sol_types;
-    /// The creation / init bytecode of the contract.
-    ///
-    /// ```text
-    ///0x610216610039600b82828239805160001a607314602c57634e487b7160e01b600052600060045260246000fd5b30600052607381538281f3fe73000000000000000000000000000000000000000030146080604052600436106100615760003560e01c80634a1ed7ff146100665780636b8f081b1461007b578063957f9b14146100c55780639ed9a84b146100cc578063f0605ba4146100d3575b600080fd5b60045b60405190815260200160405180910390f35b61006961008936600461018b565b604080516001600160a01b0383166020820152600091810182905260600160408051601f19818403018152919052805160209091012092915050565b6002610069565b6003610069565b6100696100e13660046101ad565b6040516bffffffffffffffffffffffff19606084901b1660208201526001603482015260009082906054016040516020818303038152906040528051906020012060405160200161015092919060609290921b6bffffffffffffffffffffffff19168252601482015260340190565b60408051601f1981840301815291905280516020909101209392505050565b80356001600160a01b038116811461018657600080fd5b919050565b60006020828403121561019d57600080fd5b6101a68261016f565b9392505050565b600080604083850312156101c057600080fd5b6101c98361016f565b91506101d76020840161016f565b9050925092905056fea26469706673582212204b46a84c766cc86bd12572dcc7a9c591980f50eca2f56b9630f2543fbe5d6c7e64736f6c634300081a0033
-    /// ```
-    #[rustfmt::skip]
-    #[allow(clippy::all)]
-    pub static BYTECODE: alloy_sol_types::private::Bytes = alloy_sol_types::private::Bytes::from_static(
-        b"a\x02\x16a\09`\x0B\x82\x82\x829\x80Q`\0\x1A`s\x14`,WcNH{q`\xE0\x1B`\0R`\0`\x04R`$`\0\xFD[0`\0R`s\x81S\x82\x81\xF3\xFEs\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x000\x14`\x80`@R`\x046\x10a\0aW`\x005`\xE0\x1C\x80cJ\x1E\xD7\xFF\x14a\0fW\x80ck\x8F\x08\x1B\x14a\0{W\x80c\x95\x7F\x9B\x14\x14a\0\xC5W\x80c\x9E\xD9\xA8K\x14a\0\xCCW\x80c\xF0`[\xA4\x14a\0\xD3W[`\0\x80\xFD[`\x04[`@Q\x90\x81R` \x01`@Q\x80\x91\x03\x90\xF3[a\0ia\0\x896`\x04a\x01\x8BV[`@\x80Q`\x01`\x01`\xA0\x1B\x03\x83\x16` \x82\x01R`\0\x91\x81\x01\x82\x90R``\x01`@\x80Q`\x1F\x19\x81\x84\x03\x01\x81R\x91\x90R\x80Q` \x90\x91\x01 \x92\x91PPV[`\x02a\0iV[`\x03a\0iV[a\0ia\0\xE16`\x04a\x01\xADV[`@Qk\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19``\x84\x90\x1B\x16` \x82\x01R`\x01`4\x82\x01R`\0\x90\x82\x90`T\x01`@Q` \x81\x83\x03\x03\x81R\x90`@R\x80Q\x90` \x01 `@Q` \x01a\x01P\x92\x91\x90``\x92\x90\x92\x1Bk\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x16\x82R`\x14\x82\x01R`4\x01\x90V[`@\x80Q`\x1F\x19\x81\x84\x03\x01\x81R\x91\x90R\x80Q` \x90\x91\x01 \x93\x92PPPV[\x805`\x01`\x01`\xA0\x1B\x03\x81\x16\x81\x14a\x01\x86W`\0\x80\xFD[\x91\x90PV[`\0` \x82\x84\x03\x12\x15a\x01\x9DW`\0\x80\xFD[a\x01\xA6\x82a\x01oV[\x93\x92PPPV[`\0\x80`@\x83\x85\x03\x12\x15a\x01\xC0W`\0\x80\xFD[a\x01\xC9\x83a\x01oV[\x91Pa\x01\xD7` \x84\x01a\x01oV[\x90P\x92P\x92\x90PV\xFE\xA2dipfsX\"\x12 KF\xA8Lvl\xC8k\xD1%r\xDC\xC7\xA9\xC5\x91\x98\x0FP\xEC\xA2\xF5k\x960\xF2T?\xBE]l~dsolcC\0\x08\x1A\x003",
-    );
-    /// The runtime bytecode of the contract, as deployed on the network.
-    ///
-    /// ```text
-    ///0x73000000000000000000000000000000000000000030146080604052600436106100615760003560e01c80634a1ed7ff146100665780636b8f081b1461007b578063957f9b14146100c55780639ed9a84b146100cc578063f0605ba4146100d3575b600080fd5b60045b60405190815260200160405180910390f35b61006961008936600461018b565b604080516001600160a01b0383166020820152600091810182905260600160408051601f19818403018152919052805160209091012092915050565b6002610069565b6003610069565b6100696100e13660046101ad565b6040516bffffffffffffffffffffffff19606084901b1660208201526001603482015260009082906054016040516020818303038152906040528051906020012060405160200161015092919060609290921b6bffffffffffffffffffffffff19168252601482015260340190565b60408051601f1981840301815291905280516020909101209392505050565b80356001600160a01b038116811461018657600080fd5b919050565b60006020828403121561019d57600080fd5b6101a68261016f565b9392505050565b600080604083850312156101c057600080fd5b6101c98361016f565b91506101d76020840161016f565b9050925092905056fea26469706673582212204b46a84c766cc86bd12572dcc7a9c591980f50eca2f56b9630f2543fbe5d6c7e64736f6c634300081a0033
-    /// ```
-    #[rustfmt::skip]
-    #[allow(clippy::all)]
-    pub static DEPLOYED_BYTECODE: alloy_sol_types::private::Bytes = alloy_sol_types::private::Bytes::from_static(
-        b"s\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\x000\x14`\x80`@R`\x046\x10a\0aW`\x005`\xE0\x1C\x80cJ\x1E\xD7\xFF\x14a\0fW\x80ck\x8F\x08\x1B\x14a\0{W\x80c\x95\x7F\x9B\x14\x14a\0\xC5W\x80c\x9E\xD9\xA8K\x14a\0\xCCW\x80c\xF0`[\xA4\x14a\0\xD3W[`\0\x80\xFD[`\x04[`@Q\x90\x81R` \x01`@Q\x80\x91\x03\x90\xF3[a\0ia\0\x896`\x04a\x01\x8BV[`@\x80Q`\x01`\x01`\xA0\x1B\x03\x83\x16` \x82\x01R`\0\x91\x81\x01\x82\x90R``\x01`@\x80Q`\x1F\x19\x81\x84\x03\x01\x81R\x91\x90R\x80Q` \x90\x91\x01 \x92\x91PPV[`\x02a\0iV[`\x03a\0iV[a\0ia\0\xE16`\x04a\x01\xADV[`@Qk\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19``\x84\x90\x1B\x16` \x82\x01R`\x01`4\x82\x01R`\0\x90\x82\x90`T\x01`@Q` \x81\x83\x03\x03\x81R\x90`@R\x80Q\x90` \x01 `@Q` \x01a\x01P\x92\x91\x90``\x92\x90\x92\x1Bk\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\x19\x16\x82R`\x14\x82\x01R`4\x01\x90V[`@\x80Q`\x1F\x19\x81\x84\x03\x01\x81R\x91\x90R\x80Q` \x90\x91\x01 \x93\x92PPPV[\x805`\x01`\x01`\xA0\x1B\x03\x81\x16\x81\x14a\x01\x86W`\0\x80\xFD[\x91\x90PV[`\0` \x82\x84\x03\x12\x15a\x01\x9DW`\0\x80\xFD[a\x01\xA6\x82a\x01oV[\x93\x92PPPV[`\0\x80`@\x83\x85\x03\x12\x15a\x01\xC0W`\0\x80\xFD[a\x01\xC9\x83a\x01oV[\x91Pa\x01\xD7` \x84\x01a\x01oV[\x90P\x92P\x92\x90PV\xFE\xA2dipfsX\"\x12 KF\xA8Lvl\xC8k\xD1%r\xDC\xC7\xA9\xC5\x91\x98\x0FP\xEC\xA2\xF5k\x960\xF2T?\xBE]l~dsolcC\0\x08\x1A\x003",
-    );
-    /**Function with signature `allowances_slot(address,address)` and selector `0xf0605ba4`.
-    ```solidity
-    function allowances_slot(address account, address spender) external pure returns (uint256);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct allowances_slotCall {
-        pub account: alloy::sol_types::private::Address,
-        pub spender: alloy::sol_types::private::Address,
-    }
-    ///Container type for the return parameters of the [`allowances_slot(address,address)`](allowances_slotCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct allowances_slotReturn {
-        pub _0: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Address,
-            );
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (
-                alloy::sol_types::private::Address,
-                alloy::sol_types::private::Address,
-            );
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<allowances_slotCall> for UnderlyingRustTuple<'_> {
-                fn from(value: allowances_slotCall) -> Self {
-                    (value.account, value.spender)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for allowances_slotCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {
-                        account: tuple.0,
-                        spender: tuple.1,
-                    }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::primitives::aliases::U256,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<allowances_slotReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: allowances_slotReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for allowances_slotReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for allowances_slotCall {
-            type Parameters<'a> = (
-                alloy::sol_types::sol_data::Address,
-                alloy::sol_types::sol_data::Address,
-            );
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = allowances_slotReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "allowances_slot(address,address)";
-            const SELECTOR: [u8; 4] = [240u8, 96u8, 91u8, 164u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.account,
-                    ),
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.spender,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    /**Function with signature `balances_slot(address)` and selector `0x6b8f081b`.
-    ```solidity
-    function balances_slot(address account) external pure returns (uint256);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct balances_slotCall {
-        pub account: alloy::sol_types::private::Address,
-    }
-    ///Container type for the return parameters of the [`balances_slot(address)`](balances_slotCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct balances_slotReturn {
-        pub _0: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Address,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::Address,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<balances_slotCall> for UnderlyingRustTuple<'_> {
-                fn from(value: balances_slotCall) -> Self {
-                    (value.account,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for balances_slotCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { account: tuple.0 }
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::primitives::aliases::U256,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<balances_slotReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: balances_slotReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for balances_slotReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for balances_slotCall {
-            type Parameters<'a> = (alloy::sol_types::sol_data::Address,);
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = balances_slotReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "balances_slot(address)";
-            const SELECTOR: [u8; 4] = [107u8, 143u8, 8u8, 27u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                (
-                    <alloy::sol_types::sol_data::Address as alloy_sol_types::SolType>::tokenize(
-                        &self.account,
-                    ),
-                )
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    /**Function with signature `name_slot()` and selector `0x9ed9a84b`.
-    ```solidity
-    function name_slot() external pure returns (uint256);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct name_slotCall {}
-    ///Container type for the return parameters of the [`name_slot()`](name_slotCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct name_slotReturn {
-        pub _0: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<name_slotCall> for UnderlyingRustTuple<'_> {
-                fn from(value: name_slotCall) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for name_slotCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::primitives::aliases::U256,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<name_slotReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: name_slotReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for name_slotReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for name_slotCall {
-            type Parameters<'a> = ();
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = name_slotReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "name_slot()";
-            const SELECTOR: [u8; 4] = [158u8, 217u8, 168u8, 75u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    /**Function with signature `symbol_slot()` and selector `0x4a1ed7ff`.
-    ```solidity
-    function symbol_slot() external pure returns (uint256);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct symbol_slotCall {}
-    ///Container type for the return parameters of the [`symbol_slot()`](symbol_slotCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct symbol_slotReturn {
-        pub _0: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<symbol_slotCall> for UnderlyingRustTuple<'_> {
-                fn from(value: symbol_slotCall) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for symbol_slotCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::primitives::aliases::U256,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<symbol_slotReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: symbol_slotReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for symbol_slotReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for symbol_slotCall {
-            type Parameters<'a> = ();
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = symbol_slotReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "symbol_slot()";
-            const SELECTOR: [u8; 4] = [74u8, 30u8, 215u8, 255u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    /**Function with signature `total_supply_slot()` and selector `0x957f9b14`.
-    ```solidity
-    function total_supply_slot() external pure returns (uint256);
-    ```*/
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct total_supply_slotCall {}
-    ///Container type for the return parameters of the [`total_supply_slot()`](total_supply_slotCall) function.
-    #[allow(non_camel_case_types, non_snake_case, clippy::pub_underscore_fields)]
-    #[derive(Clone)]
-    pub struct total_supply_slotReturn {
-        pub _0: alloy::sol_types::private::primitives::aliases::U256,
-    }
-    #[allow(
-        non_camel_case_types,
-        non_snake_case,
-        clippy::pub_underscore_fields,
-        clippy::style
-    )]
-    const _: () = {
-        use alloy::sol_types as alloy_sol_types;
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = ();
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = ();
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<total_supply_slotCall> for UnderlyingRustTuple<'_> {
-                fn from(value: total_supply_slotCall) -> Self {
-                    ()
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for total_supply_slotCall {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self {}
-                }
-            }
-        }
-        {
-            #[doc(hidden)]
-            type UnderlyingSolTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            #[doc(hidden)]
-            type UnderlyingRustTuple<'a> = (alloy::sol_types::private::primitives::aliases::U256,);
-            #[cfg(test)]
-            #[allow(dead_code, unreachable_patterns)]
-            fn _type_assertion(_t: alloy_sol_types::private::AssertTypeEq<UnderlyingRustTuple>) {
-                match _t {
-                    alloy_sol_types::private::AssertTypeEq::<
-                        <UnderlyingSolTuple as alloy_sol_types::SolType>::RustType,
-                    >(_) => {}
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<total_supply_slotReturn> for UnderlyingRustTuple<'_> {
-                fn from(value: total_supply_slotReturn) -> Self {
-                    (value._0,)
-                }
-            }
-            #[automatically_derived]
-            #[doc(hidden)]
-            impl ::core::convert::From<UnderlyingRustTuple<'_>> for total_supply_slotReturn {
-                fn from(tuple: UnderlyingRustTuple<'_>) -> Self {
-                    Self { _0: tuple.0 }
-                }
-            }
-        }
-        #[automatically_derived]
-        impl alloy_sol_types::SolCall for total_supply_slotCall {
-            type Parameters<'a> = ();
-            type Token<'a> = <Self::Parameters<'a> as alloy_sol_types::SolType>::Token<'a>;
-            type Return = total_supply_slotReturn;
-            type ReturnTuple<'a> = (alloy::sol_types::sol_data::Uint<256>,);
-            type ReturnToken<'a> = <Self::ReturnTuple<'a> as alloy_sol_types::SolType>::Token<'a>;
-            const SIGNATURE: &'static str = "total_supply_slot()";
-            const SELECTOR: [u8; 4] = [149u8, 127u8, 155u8, 20u8];
-            #[inline]
-            fn new<'a>(
-                tuple: <Self::Parameters<'a> as alloy_sol_types::SolType>::RustType,
-            ) -> Self {
-                tuple.into()
-            }
-            #[inline]
-            fn tokenize(&self) -> Self::Token<'_> {
-                ()
-            }
-            #[inline]
-            fn abi_decode_returns(
-                data: &[u8],
-                validate: bool,
-            ) -> alloy_sol_types::Result<Self::Return> {
-                <Self::ReturnTuple<'_> as alloy_sol_types::SolType>::abi_decode_sequence(
-                    data, validate,
-                )
-                .map(Into::into)
-            }
-        }
-    };
-    ///Container for all the [`ERC20L1Scroller`](self) function calls.
-    pub enum ERC20L1ScrollerCalls {
-        allowances_slot(allowances_slotCall),
-        balances_slot(balances_slotCall),
-        name_slot(name_slotCall),
-        symbol_slot(symbol_slotCall),
-        total_supply_slot(total_supply_slotCall),
-    }
-    #[automatically_derived]
-    impl ERC20L1ScrollerCalls {
-        /// All the selectors of this enum.
-        ///
-        /// Note that the selectors might not be in the same order as the variants.
-        /// No guarantees are made about the order of the selectors.
-        ///
-        /// Prefer using `SolInterface` methods instead.
-        pub const SELECTORS: &'static [[u8; 4usize]] = &[
-            [74u8, 30u8, 215u8, 255u8],
-            [107u8, 143u8, 8u8, 27u8],
-            [149u8, 127u8, 155u8, 20u8],
-            [158u8, 217u8, 168u8, 75u8],
-            [240u8, 96u8, 91u8, 164u8],
-        ];
-    }
-    #[automatically_derived]
-    impl alloy_sol_types::SolInterface for ERC20L1ScrollerCalls {
-        const NAME: &'static str = "ERC20L1ScrollerCalls";
-        const MIN_DATA_LENGTH: usize = 0usize;
-        const COUNT: usize = 5usize;
-        #[inline]
-        fn selector(&self) -> [u8; 4] {
-            match self {
-                Self::allowances_slot(_) => {
-                    <allowances_slotCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-                Self::balances_slot(_) => <balances_slotCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::name_slot(_) => <name_slotCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::symbol_slot(_) => <symbol_slotCall as alloy_sol_types::SolCall>::SELECTOR,
-                Self::total_supply_slot(_) => {
-                    <total_supply_slotCall as alloy_sol_types::SolCall>::SELECTOR
-                }
-            }
-        }
-        #[inline]
-        fn selector_at(i: usize) -> ::core::option::Option<[u8; 4]> {
-            Self::SELECTORS.get(i).copied()
-        }
-        #[inline]
-        fn valid_selector(selector: [u8; 4]) -> bool {
-            Self::SELECTORS.binary_search(&selector).is_ok()
-        }
-        #[inline]
-        #[allow(unsafe_code, non_snake_case)]
-        fn abi_decode_raw(
-            selector: [u8; 4],
-            data: &[u8],
-            validate: bool,
-        ) -> alloy_sol_types::Result<Self> {
-            static DECODE_SHIMS: &[fn(
-                &[u8],
-                bool,
-            )
-                -> alloy_sol_types::Result<ERC20L1ScrollerCalls>] = &[
-                {
-                    fn symbol_slot(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<ERC20L1ScrollerCalls> {
-                        <symbol_slotCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(ERC20L1ScrollerCalls::symbol_slot)
-                    }
-                    symbol_slot
-                },
-                {
-                    fn balances_slot(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<ERC20L1ScrollerCalls> {
-                        <balances_slotCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(ERC20L1ScrollerCalls::balances_slot)
-                    }
-                    balances_slot
-                },
-                {
-                    fn total_supply_slot(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<ERC20L1ScrollerCalls> {
-                        <total_supply_slotCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(ERC20L1ScrollerCalls::total_supply_slot)
-                    }
-                    total_supply_slot
-                },
-                {
-                    fn name_slot(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<ERC20L1ScrollerCalls> {
-                        <name_slotCall as alloy_sol_types::SolCall>::abi_decode_raw(data, validate)
-                            .map(ERC20L1ScrollerCalls::name_slot)
-                    }
-                    name_slot
-                },
-                {
-                    fn allowances_slot(
-                        data: &[u8],
-                        validate: bool,
-                    ) -> alloy_sol_types::Result<ERC20L1ScrollerCalls> {
-                        <allowances_slotCall as alloy_sol_types::SolCall>::abi_decode_raw(
-                            data, validate,
-                        )
-                        .map(ERC20L1ScrollerCalls::allowances_slot)
-                    }
-                    allowances_slot
-                },
-            ];
-            let Ok(idx) = Self::SELECTORS.binary_search(&selector) else {
-                return Err(alloy_sol_types::Error::unknown_selector(
-                    <Self as alloy_sol_types::SolInterface>::NAME,
-                    selector,
-                ));
-            };
-            (unsafe { DECODE_SHIMS.get_unchecked(idx) })(data, validate)
-        }
-        #[inline]
-        fn abi_encoded_size(&self) -> usize {
-            match self {
-                Self::allowances_slot(inner) => {
-                    <allowances_slotCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::balances_slot(inner) => {
-                    <balances_slotCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::name_slot(inner) => {
-                    <name_slotCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::symbol_slot(inner) => {
-                    <symbol_slotCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-                Self::total_supply_slot(inner) => {
-                    <total_supply_slotCall as alloy_sol_types::SolCall>::abi_encoded_size(inner)
-                }
-            }
-        }
-        #[inline]
-        fn abi_encode_raw(&self, out: &mut alloy_sol_types::private::Vec<u8>) {
-            match self {
-                Self::allowances_slot(inner) => {
-                    <allowances_slotCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-                Self::balances_slot(inner) => {
-                    <balances_slotCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-                Self::name_slot(inner) => {
-                    <name_slotCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-                Self::symbol_slot(inner) => {
-                    <symbol_slotCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-                Self::total_supply_slot(inner) => {
-                    <total_supply_slotCall as alloy_sol_types::SolCall>::abi_encode_raw(inner, out)
-                }
-            }
-        }
-    }
-    use alloy::contract as alloy_contract;
-    /**Creates a new wrapper around an on-chain [`ERC20L1Scroller`](self) contract instance.
-
-    See the [wrapper's documentation](`ERC20L1ScrollerInstance`) for more details.*/
-    #[inline]
-    pub const fn new<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        address: alloy_sol_types::private::Address,
-        provider: P,
-    ) -> ERC20L1ScrollerInstance<T, P, N> {
-        ERC20L1ScrollerInstance::<T, P, N>::new(address, provider)
-    }
-    /**Deploys this contract using the given `provider` and constructor arguments, if any.
-
-    Returns a new instance of the contract, if the deployment was successful.
-
-    For more fine-grained control over the deployment process, use [`deploy_builder`] instead.*/
-    #[inline]
-    pub fn deploy<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        provider: P,
-    ) -> impl ::core::future::Future<Output = alloy_contract::Result<ERC20L1ScrollerInstance<T, P, N>>>
-    {
-        ERC20L1ScrollerInstance::<T, P, N>::deploy(provider)
-    }
-    /**Creates a `RawCallBuilder` for deploying this contract using the given `provider`
-    and constructor arguments, if any.
-
-    This is a simple wrapper around creating a `RawCallBuilder` with the data set to
-    the bytecode concatenated with the constructor's ABI-encoded arguments.*/
-    #[inline]
-    pub fn deploy_builder<
-        T: alloy_contract::private::Transport + ::core::clone::Clone,
-        P: alloy_contract::private::Provider<T, N>,
-        N: alloy_contract::private::Network,
-    >(
-        provider: P,
-    ) -> alloy_contract::RawCallBuilder<T, P, N> {
-        ERC20L1ScrollerInstance::<T, P, N>::deploy_builder(provider)
-    }
-    /**A [`ERC20L1Scroller`](self) instance.
-
-    Contains type-safe methods for interacting with an on-chain instance of the
-    [`ERC20L1Scroller`](self) contract located at a given `address`, using a given
-    provider `P`.
-
-    If the contract bytecode is available (see the [`sol!`](alloy_sol_types::sol!)
-    documentation on how to provide it), the `deploy` and `deploy_builder` methods can
-    be used to deploy a new instance of the contract.
-
-    See the [module-level documentation](self) for all the available methods.*/
-    #[derive(Clone)]
-    pub struct ERC20L1ScrollerInstance<T, P, N = alloy_contract::private::Ethereum> {
-        address: alloy_sol_types::private::Address,
-        provider: P,
-        _network_transport: ::core::marker::PhantomData<(N, T)>,
-    }
-    #[automatically_derived]
-    impl<T, P, N> ::core::fmt::Debug for ERC20L1ScrollerInstance<T, P, N> {
-        #[inline]
-        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_tuple("ERC20L1ScrollerInstance")
-                .field(&self.address)
-                .finish()
-        }
-    }
-    /// Instantiation and getters/setters.
-    #[automatically_derived]
-    impl<
-            T: alloy_contract::private::Transport + ::core::clone::Clone,
-            P: alloy_contract::private::Provider<T, N>,
-            N: alloy_contract::private::Network,
-        > ERC20L1ScrollerInstance<T, P, N>
-    {
-        /**Creates a new wrapper around an on-chain [`ERC20L1Scroller`](self) contract instance.
-
-        See the [wrapper's documentation](`ERC20L1ScrollerInstance`) for more details.*/
-        #[inline]
-        pub const fn new(address: alloy_sol_types::private::Address, provider: P) -> Self {
-            Self {
-                address,
-                provider,
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-        /**Deploys this contract using the given `provider` and constructor arguments, if any.
-
-        Returns a new instance of the contract, if the deployment was successful.
-
-        For more fine-grained control over the deployment process, use [`deploy_builder`] instead.*/
-        #[inline]
-        pub async fn deploy(
-            provider: P,
-        ) -> alloy_contract::Result<ERC20L1ScrollerInstance<T, P, N>> {
-            let call_builder = Self::deploy_builder(provider);
-            let contract_address = call_builder.deploy().await?;
-            Ok(Self::new(contract_address, call_builder.provider))
-        }
-        /**Creates a `RawCallBuilder` for deploying this contract using the given `provider`
-        and constructor arguments, if any.
-
-        This is a simple wrapper around creating a `RawCallBuilder` with the data set to
-        the bytecode concatenated with the constructor's ABI-encoded arguments.*/
-        #[inline]
-        pub fn deploy_builder(provider: P) -> alloy_contract::RawCallBuilder<T, P, N> {
-            alloy_contract::RawCallBuilder::new_raw_deploy(
-                provider,
-                ::core::clone::Clone::clone(&BYTECODE),
-            )
-        }
-        /// Returns a reference to the address.
-        #[inline]
-        pub const fn address(&self) -> &alloy_sol_types::private::Address {
-            &self.address
-        }
-        /// Sets the address.
-        #[inline]
-        pub fn set_address(&mut self, address: alloy_sol_types::private::Address) {
-            self.address = address;
-        }
-        /// Sets the address and returns `self`.
-        pub fn at(mut self, address: alloy_sol_types::private::Address) -> Self {
-            self.set_address(address);
-            self
-        }
-        /// Returns a reference to the provider.
-        #[inline]
-        pub const fn provider(&self) -> &P {
-            &self.provider
-        }
-    }
-    impl<T, P: ::core::clone::Clone, N> ERC20L1ScrollerInstance<T, &P, N> {
-        /// Clones the provider and returns a new instance with the cloned provider.
-        #[inline]
-        pub fn with_cloned_provider(self) -> ERC20L1ScrollerInstance<T, P, N> {
-            ERC20L1ScrollerInstance {
-                address: self.address,
-                provider: ::core::clone::Clone::clone(&self.provider),
-                _network_transport: ::core::marker::PhantomData,
-            }
-        }
-    }
-    /// Function calls.
-    #[automatically_derived]
-    impl<
-            T: alloy_contract::private::Transport + ::core::clone::Clone,
-            P: alloy_contract::private::Provider<T, N>,
-            N: alloy_contract::private::Network,
-        > ERC20L1ScrollerInstance<T, P, N>
-    {
-        /// Creates a new call builder using this contract instance's provider and address.
-        ///
-        /// Note that the call can be any function call, not just those defined in this
-        /// contract. Prefer using the other methods for building type-safe contract calls.
-        pub fn call_builder<C: alloy_sol_types::SolCall>(
-            &self,
-            call: &C,
-        ) -> alloy_contract::SolCallBuilder<T, &P, C, N> {
-            alloy_contract::SolCallBuilder::new_sol(&self.provider, &self.address, call)
-        }
-        ///Creates a new call builder for the [`allowances_slot`] function.
-        pub fn allowances_slot(
-            &self,
-            account: alloy::sol_types::private::Address,
-            spender: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, allowances_slotCall, N> {
-            self.call_builder(&allowances_slotCall { account, spender })
-        }
-        ///Creates a new call builder for the [`balances_slot`] function.
-        pub fn balances_slot(
-            &self,
-            account: alloy::sol_types::private::Address,
-        ) -> alloy_contract::SolCallBuilder<T, &P, balances_slotCall, N> {
-            self.call_builder(&balances_slotCall { account })
-        }
-        ///Creates a new call builder for the [`name_slot`] function.
-        pub fn name_slot(&self) -> alloy_contract::SolCallBuilder<T, &P, name_slotCall, N> {
-            self.call_builder(&name_slotCall {})
-        }
-        ///Creates a new call builder for the [`symbol_slot`] function.
-        pub fn symbol_slot(&self) -> alloy_contract::SolCallBuilder<T, &P, symbol_slotCall, N> {
-            self.call_builder(&symbol_slotCall {})
-        }
-        ///Creates a new call builder for the [`total_supply_slot`] function.
-        pub fn total_supply_slot(
-            &self,
-        ) -> alloy_contract::SolCallBuilder<T, &P, total_supply_slotCall, N> {
-            self.call_builder(&total_supply_slotCall {})
-        }
-    }
-    /// Event filters.
-    #[automatically_derived]
-    impl<
-            T: alloy_contract::private::Transport + ::core::clone::Clone,
-            P: alloy_contract::private::Provider<T, N>,
-            N: alloy_contract::private::Network,
-        > ERC20L1ScrollerInstance<T, P, N>
-    {
-        /// Creates a new event filter using this contract instance's provider and address.
-        ///
-        /// Note that the type can be any event, not just those defined in this contract.
-        /// Prefer using the other methods for building type-safe event filters.
-        pub fn event_filter<E: alloy_sol_types::SolEvent>(
-            &self,
-        ) -> alloy_contract::Event<T, &P, E, N> {
-            alloy_contract::Event::new_sol(&self.provider, &self.address)
-        }
-    }
-}
@@ -1,3 +1,67 @@
-pub mod erc20l1scroller;
-pub mod l1scroller;
-pub mod nftl1scroller;
+//! Bindings for the Solidity contracts, generated by `build.rs` from their forge artifacts.
+//! `abi/` holds the artifacts used when `forge build` has not been run; the tests check
+//! their ABI against the Solidity sources.
+#[cfg(feature = "erc20")]
+pub mod erc20l1scroller {
+    include!(concat!(env!("OUT_DIR"), "/erc20l1scroller.rs"));
+}
+#[cfg(feature = "transport")]
+pub mod l1scroller {
+    include!(concat!(env!("OUT_DIR"), "/l1scroller.rs"));
+}
+#[cfg(feature = "erc721")]
+pub mod nftl1scroller {
+    include!(concat!(env!("OUT_DIR"), "/nftl1scroller.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    mod checked_in {
+        alloy::sol!(
+            #[sol(abi)]
+            L1Scroller,
+            "abi/L1Scroller.json"
+        );
+        alloy::sol!(
+            #[sol(abi)]
+            ERC20L1Scroller,
+            "abi/ERC20L1Scroller.json"
+        );
+        alloy::sol!(
+            #[sol(abi)]
+            NFTL1Scroller,
+            "abi/NFTL1Scroller.json"
+        );
+    }
+
+    mod solidity {
+        alloy::sol!(
+            #[sol(abi)]
+            "../src/L1Scroller.sol"
+        );
+        alloy::sol!(
+            #[sol(abi)]
+            "../src/utils/ERC20L1Scroller.sol"
+        );
+        alloy::sol!(
+            #[sol(abi)]
+            "../src/utils/NFTL1Scroller.sol"
+        );
+    }
+
+    #[test]
+    fn test_checked_in_abi_matches_solidity() {
+        assert_eq!(
+            checked_in::L1Scroller::abi::contract(),
+            solidity::L1Scroller::abi::contract()
+        );
+        assert_eq!(
+            checked_in::ERC20L1Scroller::abi::contract(),
+            solidity::ERC20L1Scroller::abi::contract()
+        );
+        assert_eq!(
+            checked_in::NFTL1Scroller::abi::contract(),
+            solidity::NFTL1Scroller::abi::contract()
+        );
+    }
+}
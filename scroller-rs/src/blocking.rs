@@ -0,0 +1,421 @@
+//! Synchronous wrapper around [`crate::l1scroller::Scroller`], for code that does not run
+//! an async runtime. Each `Scroller` owns a current-thread tokio runtime and blocks on it,
+//! so its methods must not be called from inside another async runtime. With the `pubsub`
+//! feature the runtime gets one worker thread instead, so `newHeads` keeps being followed
+//! between calls.
+
+use std::{pin::Pin, sync::Arc};
+
+use alloy::{
+    primitives::{
+        aliases::{U24, U48, U96},
+        Address, Bytes, B256, U128, U160, U256, U32, U64,
+    },
+    providers::Provider,
+    rpc::types::BlockId,
+    transports::Transport,
+};
+use futures::{Stream, StreamExt};
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    cache::{CacheStats, ReadOptions, SlotCache},
+    consistency::ConsistencyReport,
+    deployments::Deployments,
+    error::L1ScrollerError,
+    l1scroller::{self, L1ScrollerContracttype},
+    watch::{SlotChange, WatchConfig},
+};
+#[cfg(feature = "layout")]
+use crate::{
+    layout::{LayoutValue, LayoutVariable, MappingKey, StorageLayout},
+    snapshot::Snapshot,
+};
+
+type AsyncScroller = l1scroller::Scroller<L1ScrollerContracttype>;
+
+pub struct Scroller {
+    inner: AsyncScroller,
+    runtime: Runtime,
+}
+
+#[cfg(not(feature = "pubsub"))]
+fn runtime() -> eyre::Result<Runtime> {
+    Ok(Builder::new_current_thread().enable_all().build()?)
+}
+
+#[cfg(feature = "pubsub")]
+fn runtime() -> eyre::Result<Runtime> {
+    Ok(Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_all()
+        .build()?)
+}
+
+impl Scroller {
+    #[cfg(feature = "http")]
+    pub fn new(rpc_url: &str, scroller_addr: Address) -> eyre::Result<Self> {
+        Ok(Self {
+            inner: AsyncScroller::new(rpc_url, scroller_addr)?,
+            runtime: runtime()?,
+        })
+    }
+
+    /// See [`l1scroller::Scroller::direct`].
+    #[cfg(feature = "http")]
+    pub fn direct(rpc_url: &str) -> eyre::Result<Self> {
+        Ok(Self {
+            inner: AsyncScroller::direct(rpc_url)?,
+            runtime: runtime()?,
+        })
+    }
+
+    /// See [`l1scroller::Scroller::connect_to`].
+    pub fn connect_to(rpc_url: &str, scroller_addr: Option<Address>) -> eyre::Result<Self> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(AsyncScroller::connect_to(rpc_url, scroller_addr))?;
+        Ok(Self { inner, runtime })
+    }
+
+    /// See [`l1scroller::Scroller::connect`].
+    pub fn connect(rpc_url: &str) -> eyre::Result<Self> {
+        Self::connect_with(rpc_url, &Deployments::builtin())
+    }
+
+    pub fn connect_with(rpc_url: &str, deployments: &Deployments) -> eyre::Result<Self> {
+        let runtime = runtime()?;
+        let inner = runtime.block_on(AsyncScroller::connect_with(rpc_url, deployments))?;
+        Ok(Self { inner, runtime })
+    }
+
+    pub fn with_cache(self, cache: Arc<dyn SlotCache>) -> Self {
+        Self {
+            inner: self.inner.with_cache(cache),
+            ..self
+        }
+    }
+
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.inner.cache_stats()
+    }
+
+    pub fn scroller_address(&self) -> Option<Address> {
+        self.inner.scroller_address()
+    }
+
+    /// The wrapped async `Scroller`.
+    pub fn inner(&self) -> &AsyncScroller {
+        &self.inner
+    }
+
+    pub fn read_slot(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<Bytes, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_slot(l1_contract_addr, slot))
+    }
+
+    pub fn read_slot_at(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+        block: BlockId,
+    ) -> eyre::Result<Bytes, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_slot_at(l1_contract_addr, slot, block))
+    }
+
+    pub fn read_multiple_slots(
+        &self,
+        l1_contract_addr: Address,
+        slot: Vec<U256>,
+    ) -> eyre::Result<Bytes, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_multiple_slots(l1_contract_addr, slot))
+    }
+
+    pub fn read_multiple_slots_at(
+        &self,
+        l1_contract_addr: Address,
+        slot: Vec<U256>,
+        block: BlockId,
+    ) -> eyre::Result<Bytes, L1ScrollerError> {
+        self.runtime.block_on(
+            self.inner
+                .read_multiple_slots_at(l1_contract_addr, slot, block),
+        )
+    }
+
+    pub fn read_slots_direct(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+    ) -> eyre::Result<Vec<B256>, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_slots_direct(l1_contract_addr, slots))
+    }
+
+    pub fn read_slots_direct_at(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        block: BlockId,
+    ) -> eyre::Result<Vec<B256>, L1ScrollerError> {
+        self.runtime.block_on(
+            self.inner
+                .read_slots_direct_at(l1_contract_addr, slots, block),
+        )
+    }
+
+    pub fn read_slot_direct(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<B256, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_slot_direct(l1_contract_addr, slot))
+    }
+
+    pub fn l1_block_number(&self) -> eyre::Result<u64, L1ScrollerError> {
+        self.runtime.block_on(self.inner.l1_block_number())
+    }
+
+    pub fn l1_block_number_at(&self, block: BlockId) -> eyre::Result<u64, L1ScrollerError> {
+        self.runtime.block_on(self.inner.l1_block_number_at(block))
+    }
+
+    pub fn l2_block_number(&self) -> eyre::Result<u64, L1ScrollerError> {
+        self.runtime.block_on(self.inner.l2_block_number())
+    }
+
+    /// Batched and cached reads, see [`l1scroller::Scroller::read_words`].
+    pub fn read_words(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        options: ReadOptions,
+    ) -> eyre::Result<Vec<B256>, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_words(l1_contract_addr, slots, options))
+    }
+
+    pub fn read_word(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+        options: ReadOptions,
+    ) -> eyre::Result<B256, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_word(l1_contract_addr, slot, options))
+    }
+
+    pub fn read_words_at(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        block: BlockId,
+    ) -> eyre::Result<Vec<B256>, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_words_at(l1_contract_addr, slots, block))
+    }
+
+    pub fn read_uint(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U256, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint160(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U160, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint160(l1_contract_addr, slot))
+    }
+
+    pub fn read_address(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<Address, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_address(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint96(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U96, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint96(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint48(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U48, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint48(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint24(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U24, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint24(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint8(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<u8, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint8(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint32(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U32, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint32(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint64(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U64, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint64(l1_contract_addr, slot))
+    }
+
+    pub fn read_uint128(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<U128, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_uint128(l1_contract_addr, slot))
+    }
+
+    pub fn read_string(
+        &self,
+        l1_contract_addr: Address,
+        slot: U256,
+    ) -> eyre::Result<String, L1ScrollerError> {
+        self.runtime
+            .block_on(self.inner.read_string(l1_contract_addr, slot))
+    }
+
+    #[cfg(feature = "layout")]
+    pub fn read_layout(
+        &self,
+        l1_contract_addr: Address,
+        layout: &StorageLayout,
+        keys: &[MappingKey],
+        block: BlockId,
+    ) -> eyre::Result<Vec<LayoutValue>, L1ScrollerError> {
+        self.runtime.block_on(
+            self.inner
+                .read_layout(l1_contract_addr, layout, keys, block),
+        )
+    }
+
+    #[cfg(feature = "layout")]
+    pub fn snapshot(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        variables: &[LayoutVariable],
+        block: BlockId,
+    ) -> eyre::Result<Snapshot, L1ScrollerError> {
+        self.runtime.block_on(
+            self.inner
+                .snapshot(l1_contract_addr, slots, variables, block),
+        )
+    }
+
+    pub fn check_consistency<T, P>(
+        &self,
+        l1_provider: &P,
+        l1_contract_addr: Address,
+        slots: &[U256],
+    ) -> eyre::Result<ConsistencyReport, L1ScrollerError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        self.runtime.block_on(
+            self.inner
+                .check_consistency(l1_provider, l1_contract_addr, slots),
+        )
+    }
+
+    /// Blocking iterator over [`l1scroller::Scroller::watch_with`]; `next` waits for the
+    /// next change.
+    pub fn watch_with(
+        &self,
+        l1_contract_addr: Address,
+        slots: Vec<U256>,
+        config: WatchConfig,
+    ) -> Watch<'_> {
+        Watch {
+            runtime: &self.runtime,
+            stream: Box::pin(self.inner.watch_with(l1_contract_addr, slots, config)),
+        }
+    }
+
+    pub fn watch(&self, l1_contract_addr: Address, slots: Vec<U256>) -> Watch<'_> {
+        self.watch_with(l1_contract_addr, slots, WatchConfig::default())
+    }
+}
+
+/// Iterator returned by [`Scroller::watch`]. It never ends on its own.
+pub struct Watch<'a> {
+    runtime: &'a Runtime,
+    stream: Pin<Box<dyn Stream<Item = eyre::Result<SlotChange, L1ScrollerError>> + 'a>>,
+}
+
+impl Iterator for Watch<'_> {
+    type Item = eyre::Result<SlotChange, L1ScrollerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+#[cfg(all(test, feature = "http"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks_on_own_runtime() {
+        let scroller = Scroller::direct("http://127.0.0.1:1").unwrap();
+        assert!(matches!(
+            scroller.l2_block_number(),
+            Err(L1ScrollerError::BlockNumberError)
+        ));
+        assert!(matches!(
+            scroller.read_words(Address::ZERO, &[U256::ZERO], ReadOptions::default()),
+            Err(L1ScrollerError::PrecompileCallError)
+        ));
+        assert!(scroller
+            .watch(Address::ZERO, vec![U256::ZERO])
+            .next()
+            .unwrap()
+            .is_err());
+    }
+}
@@ -0,0 +1,374 @@
+#[cfg(feature = "cache")]
+use std::{
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+// `std::time::Instant::now` panics on wasm32-unknown-unknown.
+#[cfg(all(feature = "cache", not(target_arch = "wasm32")))]
+use std::time::Instant;
+#[cfg(all(feature = "cache", target_arch = "wasm32"))]
+use web_time::Instant;
+
+use alloy::primitives::{Address, B256, U256};
+#[cfg(feature = "cache")]
+use lru::LruCache;
+
+#[cfg(feature = "cache")]
+/// Default number of entries kept by [`LruSlotCache`].
+pub const DEFAULT_CACHE_CAPACITY: usize = 10_000;
+
+/// A slot value as observed at an L1 block through L1SLOAD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlotKey {
+    pub l1_contract: Address,
+    pub slot: U256,
+    pub l1_block: u64,
+}
+
+impl SlotKey {
+    pub fn new(l1_contract: Address, slot: U256, l1_block: u64) -> Self {
+        Self {
+            l1_contract,
+            slot,
+            l1_block,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub inserts: u64,
+    /// Entries dropped because the cache was full.
+    pub evictions: u64,
+    /// Entries dropped because they outlived the TTL.
+    pub expirations: u64,
+    /// Storage failures of persistent caches, each treated as a miss.
+    pub errors: u64,
+    pub entries: usize,
+}
+
+impl CacheStats {
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / lookups as f64
+    }
+}
+
+/// Storage for slot reads, shared by every [`Scroller`](crate::l1scroller::Scroller)
+/// it is attached to.
+///
+/// Regular entries are only valid for the L1 block they were read at. Entries inserted
+/// with [`SlotCache::insert_immutable`] are returned for any block and never expire,
+/// which suits values such as token names, symbols and decimals.
+pub trait SlotCache: Send + Sync {
+    /// Value of the slot at `key.l1_block`, falling back to an immutable entry.
+    fn get(&self, key: &SlotKey) -> Option<B256>;
+
+    /// Value of the slot if it was marked immutable.
+    fn get_immutable(&self, l1_contract: Address, slot: U256) -> Option<B256>;
+
+    fn insert(&self, key: SlotKey, value: B256);
+
+    fn insert_immutable(&self, l1_contract: Address, slot: U256, value: B256);
+
+    /// Drops every entry for the slot, immutable or not.
+    fn invalidate(&self, l1_contract: Address, slot: U256);
+
+    fn clear(&self);
+
+    /// Called when the L1 block visible on L2 advanced to `l1_block`, to drop entries that
+    /// can no longer be served. Immutable entries are kept. Does nothing by default.
+    fn expire_before(&self, _l1_block: u64) {}
+
+    fn stats(&self) -> CacheStats;
+}
+
+#[cfg(feature = "cache")]
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    inserts: AtomicU64,
+    evictions: AtomicU64,
+    expirations: AtomicU64,
+}
+
+#[cfg(feature = "cache")]
+impl Counters {
+    fn record_lookup(&self, hit: bool) {
+        let counter = if hit { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "cache")]
+#[derive(Debug)]
+struct Entries {
+    by_block: LruCache<SlotKey, (B256, Instant)>,
+    immutable: HashMap<(Address, U256), B256>,
+}
+
+#[cfg(feature = "cache")]
+/// In-memory [`SlotCache`] that evicts the least recently used entries once
+/// `capacity` is reached and, optionally, entries older than a TTL.
+///
+/// Immutable entries are not counted against the capacity.
+#[derive(Debug)]
+pub struct LruSlotCache {
+    entries: Mutex<Entries>,
+    ttl: Option<Duration>,
+    counters: Counters,
+}
+
+#[cfg(feature = "cache")]
+impl Default for LruSlotCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl LruSlotCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(Entries {
+                by_block: LruCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+                immutable: HashMap::new(),
+            }),
+            ttl: None,
+            counters: Counters::default(),
+        }
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    fn expired(&self, inserted: Instant) -> bool {
+        self.ttl.is_some_and(|ttl| inserted.elapsed() >= ttl)
+    }
+}
+
+#[cfg(feature = "cache")]
+impl SlotCache for LruSlotCache {
+    fn get(&self, key: &SlotKey) -> Option<B256> {
+        let mut entries = self.entries.lock().unwrap();
+        let value = match entries.by_block.get(key) {
+            Some((_, inserted)) if self.expired(*inserted) => {
+                entries.by_block.pop(key);
+                self.counters.expirations.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            Some((value, _)) => Some(*value),
+            None => None,
+        }
+        .or_else(|| entries.immutable.get(&(key.l1_contract, key.slot)).copied());
+        self.counters.record_lookup(value.is_some());
+        value
+    }
+
+    fn get_immutable(&self, l1_contract: Address, slot: U256) -> Option<B256> {
+        let value = self
+            .entries
+            .lock()
+            .unwrap()
+            .immutable
+            .get(&(l1_contract, slot))
+            .copied();
+        self.counters.record_lookup(value.is_some());
+        value
+    }
+
+    fn insert(&self, key: SlotKey, value: B256) {
+        let evicted = self
+            .entries
+            .lock()
+            .unwrap()
+            .by_block
+            .push(key, (value, Instant::now()));
+        if evicted.is_some_and(|(evicted_key, _)| evicted_key != key) {
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+        self.counters.inserts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn insert_immutable(&self, l1_contract: Address, slot: U256, value: B256) {
+        self.entries
+            .lock()
+            .unwrap()
+            .immutable
+            .insert((l1_contract, slot), value);
+        self.counters.inserts.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn invalidate(&self, l1_contract: Address, slot: U256) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.immutable.remove(&(l1_contract, slot));
+        let stale: Vec<SlotKey> = entries
+            .by_block
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| key.l1_contract == l1_contract && key.slot == slot)
+            .collect();
+        for key in stale {
+            entries.by_block.pop(&key);
+        }
+    }
+
+    fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.by_block.clear();
+        entries.immutable.clear();
+    }
+
+    fn expire_before(&self, l1_block: u64) {
+        let mut entries = self.entries.lock().unwrap();
+        let expired: Vec<SlotKey> = entries
+            .by_block
+            .iter()
+            .map(|(key, _)| *key)
+            .filter(|key| key.l1_block < l1_block)
+            .collect();
+        for key in &expired {
+            entries.by_block.pop(key);
+        }
+        self.counters
+            .expirations
+            .fetch_add(expired.len() as u64, Ordering::Relaxed);
+    }
+
+    fn stats(&self) -> CacheStats {
+        let entries = self.entries.lock().unwrap();
+        CacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            inserts: self.counters.inserts.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+            expirations: self.counters.expirations.load(Ordering::Relaxed),
+            errors: 0,
+            entries: entries.by_block.len() + entries.immutable.len(),
+        }
+    }
+}
+
+/// Per-read cache behaviour for [`Scroller::read_words`](crate::l1scroller::Scroller::read_words).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReadOptions {
+    /// Skip the cache lookup. The fresh value is still stored.
+    pub bypass_cache: bool,
+    /// Treat the slots as never changing: they are served for any L1 block, never expire
+    /// and no L1 block lookup is needed when they are cached.
+    pub immutable: bool,
+    /// L1 block the read is attributed to. When unset, the latest L1 block is read from
+    /// the L1Blocks predeploy, and looked up again at the pinned L2 block on a cache miss;
+    /// passing a block already fetched saves these calls.
+    pub l1_block: Option<u64>,
+    /// L2 block the read is executed at. When unset, reads fitting in one batch run at the
+    /// latest block, and larger ones or cache misses look its number up once and use it
+    /// for every batch. `l1_block` must be the L1 block visible at this L2 block.
+    pub l2_block: Option<u64>,
+}
+
+impl ReadOptions {
+    pub fn bypass() -> Self {
+        Self {
+            bypass_cache: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn immutable() -> Self {
+        Self {
+            immutable: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn at_l1_block(mut self, l1_block: u64) -> Self {
+        self.l1_block = Some(l1_block);
+        self
+    }
+
+    pub fn at_l2_block(mut self, l2_block: u64) -> Self {
+        self.l2_block = Some(l2_block);
+        self
+    }
+}
+
+#[cfg(all(test, feature = "cache"))]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const TOKEN: Address = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+
+    #[test]
+    fn test_lru_keyed_by_block() {
+        let cache = LruSlotCache::new(2);
+        let key = SlotKey::new(TOKEN, U256::from(1), 100);
+        cache.insert(key, B256::with_last_byte(1));
+
+        assert_eq!(cache.get(&key), Some(B256::with_last_byte(1)));
+        assert_eq!(
+            cache.get(&SlotKey {
+                l1_block: 101,
+                ..key
+            }),
+            None
+        );
+
+        cache.insert(SlotKey::new(TOKEN, U256::from(2), 100), B256::ZERO);
+        cache.insert(SlotKey::new(TOKEN, U256::from(3), 100), B256::ZERO);
+        assert_eq!(cache.get(&key), None);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses), (1, 2));
+        assert_eq!((stats.inserts, stats.evictions, stats.entries), (3, 1, 2));
+    }
+
+    #[test]
+    fn test_ttl_and_immutable() {
+        let cache = LruSlotCache::new(4).with_ttl(Duration::ZERO);
+        let key = SlotKey::new(TOKEN, U256::from(3), 100);
+        cache.insert(key, B256::with_last_byte(1));
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.stats().expirations, 1);
+
+        let name = B256::with_last_byte(0x0a);
+        cache.insert_immutable(TOKEN, U256::from(3), name);
+        assert_eq!(cache.get(&key), Some(name));
+        assert_eq!(cache.get_immutable(TOKEN, U256::from(3)), Some(name));
+
+        cache.invalidate(TOKEN, U256::from(3));
+        assert_eq!(cache.get_immutable(TOKEN, U256::from(3)), None);
+    }
+
+    #[test]
+    fn test_expire_before() {
+        let cache = LruSlotCache::new(4);
+        let old = SlotKey::new(TOKEN, U256::from(1), 100);
+        let new = SlotKey::new(TOKEN, U256::from(1), 101);
+        cache.insert(old, B256::with_last_byte(1));
+        cache.insert(new, B256::with_last_byte(2));
+        cache.insert_immutable(TOKEN, U256::from(3), B256::with_last_byte(3));
+
+        cache.expire_before(101);
+        assert_eq!(cache.get(&old), None);
+        assert_eq!(cache.get(&new), Some(B256::with_last_byte(2)));
+        assert!(cache.get_immutable(TOKEN, U256::from(3)).is_some());
+        assert_eq!((cache.stats().expirations, cache.stats().entries), (1, 2));
+    }
+}
@@ -0,0 +1,214 @@
+use alloy::{
+    primitives::{Address, B256, U256},
+    providers::Provider,
+    rpc::types::BlockId,
+    transports::Transport,
+};
+use serde::Serialize;
+
+use crate::{
+    error::L1ScrollerError,
+    l1scroller::{L1ScrollerContracttype, Scroller},
+    precompile::decode_l1sload_output,
+};
+
+/// The same slot read through L1SLOAD on L2 and with `eth_getStorageAt` on L1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SlotCheck {
+    pub slot: U256,
+    pub l2: B256,
+    pub l1: B256,
+}
+
+impl SlotCheck {
+    pub fn matches(&self) -> bool {
+        self.l2 == self.l1
+    }
+}
+
+/// Result of [`Scroller::check_consistency`]. Both sides were read at `l1_block`, the L1
+/// block visible through L1Blocks at `l2_block`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyReport {
+    pub l1_contract: Address,
+    pub l2_block: u64,
+    pub l1_block: u64,
+    pub checks: Vec<SlotCheck>,
+}
+
+impl ConsistencyReport {
+    pub fn mismatches(&self) -> impl Iterator<Item = &SlotCheck> {
+        self.checks.iter().filter(|check| !check.matches())
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches().next().is_none()
+    }
+}
+
+fn compare(slots: &[U256], l2: &[B256], l1: &[B256]) -> Vec<SlotCheck> {
+    slots
+        .iter()
+        .zip(l2.iter().zip(l1))
+        .map(|(slot, (l2, l1))| SlotCheck {
+            slot: *slot,
+            l2: *l2,
+            l1: *l1,
+        })
+        .collect()
+}
+
+impl Scroller<L1ScrollerContracttype> {
+    /// Reads `slots` with [`Scroller::read_slot`] at the latest L2 block, and with
+    /// `eth_getStorageAt` on `l1_provider` at the L1 block L1Blocks reports for that L2
+    /// block, so that both reads see the same L1 state.
+    ///
+    /// A `Scroller` created with [`Scroller::direct`] reads through the L1SLOAD precompile
+    /// instead of an L1Scroller deployment.
+    pub async fn check_consistency<T, P>(
+        &self,
+        l1_provider: &P,
+        l1_contract_addr: Address,
+        slots: &[U256],
+    ) -> eyre::Result<ConsistencyReport, L1ScrollerError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let l2_block = self.l2_block_number().await?;
+        self.check_consistency_at(l1_provider, l1_contract_addr, slots, l2_block)
+            .await
+    }
+
+    /// Like [`Scroller::check_consistency`], reading L2 at `l2_block`.
+    pub async fn check_consistency_at<T, P>(
+        &self,
+        l1_provider: &P,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        l2_block: u64,
+    ) -> eyre::Result<ConsistencyReport, L1ScrollerError>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let at = BlockId::number(l2_block);
+        let l1_block = self.l1_block_number_at(at).await?;
+
+        let l2 = if self.scroller_address().is_none() {
+            self.read_words_at(l1_contract_addr, slots, at).await?
+        } else {
+            let mut words = Vec::with_capacity(slots.len());
+            for slot in slots {
+                let output = self.read_slot_at(l1_contract_addr, *slot, at).await?;
+                words.extend(decode_l1sload_output(&output, 1)?);
+            }
+            words
+        };
+
+        let mut l1 = Vec::with_capacity(slots.len());
+        for slot in slots {
+            let value = l1_provider
+                .get_storage_at(l1_contract_addr, *slot)
+                .block_id(BlockId::number(l1_block))
+                .await
+                .map_err(|_| L1ScrollerError::L1StorageError)?;
+            l1.push(B256::from(value));
+        }
+
+        Ok(ConsistencyReport {
+            l1_contract: l1_contract_addr,
+            l2_block,
+            l1_block,
+            checks: compare(slots, &l2, &l1),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_check_consistency_at() {
+        use alloy::{hex, primitives::Bytes, rpc::json_rpc::ErrorPayload};
+        use serde_json::json;
+
+        use crate::{
+            mock::MockTransport,
+            precompile::{L1SLOAD_MAX_SLOTS, L1_BLOCKS_ADDRESS},
+        };
+
+        // L2 at block 100 sees L1 block 25, and L1SLOAD returns every slot as its value.
+        let l2 = MockTransport::new(|method, params| {
+            let to = params[0]["to"].as_str().and_then(|to| to.parse().ok());
+            match method {
+                "eth_call" if to == Some(L1_BLOCKS_ADDRESS) => {
+                    Ok(json!(B256::from(U256::from(25))))
+                }
+                // The slots after the 20-byte address are also the words read.
+                "eth_call" => {
+                    let input = hex::decode(params[0]["input"].as_str().unwrap()).unwrap();
+                    Ok(json!(Bytes::copy_from_slice(&input[20..])))
+                }
+                _ => Err(ErrorPayload::method_not_found()),
+            }
+        });
+        // L1 agrees, except for slot 3.
+        let l1 = MockTransport::new(|method, params| match method {
+            "eth_getStorageAt" => {
+                let slot: U256 = serde_json::from_value(params[1].clone()).unwrap();
+                let value = if slot == U256::from(3) {
+                    slot + U256::from(1)
+                } else {
+                    slot
+                };
+                Ok(json!(value))
+            }
+            _ => Err(ErrorPayload::method_not_found()),
+        });
+
+        let scroller = Scroller::with_provider(l2.provider(), None);
+        let slots: Vec<U256> = (0..L1SLOAD_MAX_SLOTS as u64 + 2).map(U256::from).collect();
+        let report = scroller
+            .check_consistency_at(&l1.provider(), Address::ZERO, &slots, 100)
+            .await
+            .unwrap();
+
+        assert_eq!((report.l2_block, report.l1_block), (100, 25));
+        assert_eq!(report.checks.len(), slots.len());
+        let mismatches: Vec<_> = report.mismatches().collect();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].slot, U256::from(3));
+        assert_eq!(mismatches[0].l2, B256::from(U256::from(3)));
+        assert_eq!(mismatches[0].l1, B256::from(U256::from(4)));
+
+        // The L1Blocks lookup and two L1SLOAD batches at the L2 block, and every L1 read
+        // at the L1 block it reported.
+        let calls = l2.params("eth_call");
+        assert_eq!(calls.len(), 3);
+        assert!(calls.iter().all(|params| params[1] == json!("0x64")));
+        let reads = l1.params("eth_getStorageAt");
+        assert_eq!(reads.len(), slots.len());
+        assert!(reads.iter().all(|params| params[2] == json!("0x19")));
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let slots = [U256::from(0), U256::from(1)];
+        let l2 = [B256::with_last_byte(1), B256::with_last_byte(2)];
+        let l1 = [B256::with_last_byte(1), B256::with_last_byte(3)];
+        let report = ConsistencyReport {
+            l1_contract: Address::ZERO,
+            l2_block: 100,
+            l1_block: 20,
+            checks: compare(&slots, &l2, &l1),
+        };
+        assert!(!report.is_consistent());
+        let mismatches: Vec<_> = report.mismatches().collect();
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].slot, U256::from(1));
+    }
+}
@@ -0,0 +1,240 @@
+use std::{path::Path, str::FromStr};
+
+use alloy::{
+    network::{EthereumWallet, TransactionBuilder},
+    primitives::{Address, Bytes, TxHash},
+    providers::{Provider, ProviderBuilder},
+    rpc::types::TransactionRequest,
+    signers::local::PrivateKeySigner,
+    transports::{http::reqwest::Url, Transport},
+};
+
+use crate::{
+    bindings::{
+        erc20l1scroller::ERC20L1Scroller, l1scroller::L1Scroller, nftl1scroller::NFTL1Scroller,
+    },
+    deployments::{Deployment, Deployments},
+    error::L1ScrollerError,
+    gas::send_with_fee_bump,
+};
+
+/// Gas limit used for every deployment. Gas estimation on Scroll RPCs often fails with
+/// "intrinsic gas too low", so an explicit limit is always set.
+pub const DEFAULT_DEPLOY_GAS_LIMIT: u64 = 1_000_000;
+
+/// Fee floor (in wei) known to get transactions included on the L1SLOAD devnet.
+pub const DEFAULT_MIN_FEE_PER_GAS: u128 = 551_000_000;
+
+#[derive(Debug, Clone)]
+pub struct DeployConfig {
+    pub gas_limit: u64,
+    /// Lower bound for `max_fee_per_gas`; the node's suggestion is used when higher.
+    pub min_max_fee_per_gas: u128,
+    /// Lower bound for `max_priority_fee_per_gas`; the node's suggestion is used when higher.
+    pub min_priority_fee_per_gas: u128,
+    /// Percentage both fees are raised by after a "replacement transaction underpriced" error.
+    pub fee_bump_percent: u128,
+    pub max_fee_bumps: u32,
+    pub confirmations: u64,
+}
+
+impl Default for DeployConfig {
+    fn default() -> Self {
+        Self {
+            gas_limit: DEFAULT_DEPLOY_GAS_LIMIT,
+            min_max_fee_per_gas: DEFAULT_MIN_FEE_PER_GAS,
+            min_priority_fee_per_gas: DEFAULT_MIN_FEE_PER_GAS,
+            fee_bump_percent: 20,
+            max_fee_bumps: 5,
+            confirmations: 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployedContract {
+    pub address: Address,
+    pub tx_hash: TxHash,
+    pub block: Option<u64>,
+}
+
+/// Result of [`deploy_suite`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployedSuite {
+    pub chain_id: u64,
+    pub l1_scroller: DeployedContract,
+    pub erc20_l1scroller: DeployedContract,
+    pub nft_l1scroller: DeployedContract,
+}
+
+impl DeployedSuite {
+    pub fn deployment(&self) -> Deployment {
+        Deployment {
+            l1_scroller: self.l1_scroller.address,
+            erc20_l1scroller: Some(self.erc20_l1scroller.address),
+            nft_l1scroller: Some(self.nft_l1scroller.address),
+            block: self.l1_scroller.block,
+        }
+    }
+
+    /// Adds this suite to `deployments`, replacing any previous entry for the chain.
+    pub fn record(&self, deployments: &mut Deployments) {
+        deployments.insert(self.chain_id, self.deployment());
+    }
+
+    /// Records this suite into the deployments file at `path`, creating it if needed.
+    pub fn record_to_file(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        let path = path.as_ref();
+        let mut deployments = if path.exists() {
+            Deployments::from_file(path)?
+        } else {
+            Deployments::default()
+        };
+        self.record(&mut deployments);
+        deployments.to_file(path)
+    }
+}
+
+/// Deploys L1Scroller, ERC20L1Scroller and NFTL1Scroller with `signer` and verifies
+/// the runtime bytecode of each.
+pub async fn deploy_suite(
+    rpc_url: &str,
+    signer: PrivateKeySigner,
+    config: &DeployConfig,
+) -> eyre::Result<DeployedSuite> {
+    let url = Url::from_str(rpc_url)?;
+    let from = signer.address();
+    let pr = ProviderBuilder::new()
+        .with_recommended_fillers()
+        .wallet(EthereumWallet::from(signer))
+        .on_http(url);
+    let chain_id = pr
+        .get_chain_id()
+        .await
+        .map_err(|_| L1ScrollerError::ChainIdError)?;
+
+    let l1_scroller = deploy_contract(
+        &pr,
+        from,
+        &L1Scroller::BYTECODE,
+        &L1Scroller::DEPLOYED_BYTECODE,
+        config,
+    )
+    .await?;
+    let erc20_l1scroller = deploy_contract(
+        &pr,
+        from,
+        &ERC20L1Scroller::BYTECODE,
+        &ERC20L1Scroller::DEPLOYED_BYTECODE,
+        config,
+    )
+    .await?;
+    let nft_l1scroller = deploy_contract(
+        &pr,
+        from,
+        &NFTL1Scroller::BYTECODE,
+        &NFTL1Scroller::DEPLOYED_BYTECODE,
+        config,
+    )
+    .await?;
+
+    Ok(DeployedSuite {
+        chain_id,
+        l1_scroller,
+        erc20_l1scroller,
+        nft_l1scroller,
+    })
+}
+
+/// Sends a contract creation transaction with an explicit gas limit, raising the fees
+/// whenever the node rejects it as underpriced, then checks the deployed runtime code.
+///
+/// See [`send_with_fee_bump`].
+pub async fn deploy_contract<T, P>(
+    provider: &P,
+    from: Address,
+    creation_code: &Bytes,
+    runtime_code: &Bytes,
+    config: &DeployConfig,
+) -> eyre::Result<DeployedContract>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let fees = provider.estimate_eip1559_fees(None).await?;
+    let max_priority_fee_per_gas = fees
+        .max_priority_fee_per_gas
+        .max(config.min_priority_fee_per_gas);
+    let max_fee_per_gas = fees
+        .max_fee_per_gas
+        .max(config.min_max_fee_per_gas)
+        .max(max_priority_fee_per_gas);
+    let tx = TransactionRequest::default()
+        .with_from(from)
+        .with_deploy_code(creation_code.clone())
+        .with_gas_limit(config.gas_limit)
+        .with_max_fee_per_gas(max_fee_per_gas)
+        .with_max_priority_fee_per_gas(max_priority_fee_per_gas);
+    let pending =
+        send_with_fee_bump(provider, tx, config.fee_bump_percent, config.max_fee_bumps).await?;
+
+    let receipt = pending
+        .with_required_confirmations(config.confirmations)
+        .get_receipt()
+        .await?;
+    let tx_hash = receipt.transaction_hash;
+    if !receipt.status() {
+        return Err(L1ScrollerError::DeploymentReverted(tx_hash).into());
+    }
+    let address = receipt
+        .contract_address
+        .ok_or(L1ScrollerError::DeploymentReverted(tx_hash))?;
+
+    let code = provider.get_code_at(address).await?;
+    if code != expected_runtime_code(runtime_code, address) {
+        return Err(L1ScrollerError::BytecodeMismatch(address).into());
+    }
+
+    Ok(DeployedContract {
+        address,
+        tx_hash,
+        block: receipt.block_number,
+    })
+}
+
+/// Runtime code expected at `address` for a contract compiled to `runtime_code`.
+///
+/// Libraries with public functions start with `PUSH20 <address>` to guard against
+/// delegatecalls; solc leaves the address zeroed and the EVM fills it in at deployment.
+pub fn expected_runtime_code(runtime_code: &Bytes, address: Address) -> Bytes {
+    let is_library = runtime_code.len() > 21
+        && runtime_code[0] == 0x73
+        && runtime_code[1..21].iter().all(|b| *b == 0);
+    if !is_library {
+        return runtime_code.clone();
+    }
+    let mut code = runtime_code.to_vec();
+    code[1..21].copy_from_slice(address.as_slice());
+    code.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    #[test]
+    fn test_expected_runtime_code() {
+        let addr = address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+
+        let scroller = expected_runtime_code(&L1Scroller::DEPLOYED_BYTECODE, addr);
+        assert_eq!(scroller, L1Scroller::DEPLOYED_BYTECODE);
+
+        let library = expected_runtime_code(&ERC20L1Scroller::DEPLOYED_BYTECODE, addr);
+        assert_eq!(library.len(), ERC20L1Scroller::DEPLOYED_BYTECODE.len());
+        assert_eq!(library[0], 0x73);
+        assert_eq!(&library[1..21], addr.as_slice());
+        assert_eq!(library[21..], ERC20L1Scroller::DEPLOYED_BYTECODE[21..]);
+    }
+}
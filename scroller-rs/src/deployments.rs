@@ -0,0 +1,144 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use alloy::primitives::{address, Address};
+use serde::{Deserialize, Serialize};
+
+/// Chain id of the Scroll L1SLOAD devnet (`https://l1sload-rpc.scroll.io`).
+pub const L1SLOAD_DEVNET_CHAIN_ID: u64 = 2227728;
+
+/// Addresses of the L1Scroller contract suite on a single chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    pub l1_scroller: Address,
+    #[serde(
+        default,
+        rename = "erc20L1Scroller",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub erc20_l1scroller: Option<Address>,
+    #[serde(
+        default,
+        rename = "nftL1Scroller",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub nft_l1scroller: Option<Address>,
+    /// Block at which `l1_scroller` was deployed, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block: Option<u64>,
+}
+
+/// Registry of known deployments keyed by chain id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Deployments {
+    by_chain: BTreeMap<u64, Deployment>,
+}
+
+impl Deployments {
+    /// Deployments shipped with the crate.
+    pub fn builtin() -> Self {
+        let mut deployments = Self::default();
+        deployments.insert(
+            L1SLOAD_DEVNET_CHAIN_ID,
+            Deployment {
+                l1_scroller: address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC"),
+                erc20_l1scroller: None,
+                nft_l1scroller: None,
+                block: None,
+            },
+        );
+        deployments
+    }
+
+    /// Parses a JSON object of the form `{ "<chain id>": { "l1Scroller": "0x..", ... } }`.
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        Self::from_json(&fs::read_to_string(path)?)
+    }
+
+    /// Returns the builtin deployments with the entries from `path` taking precedence.
+    pub fn builtin_with_overrides(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let mut deployments = Self::builtin();
+        deployments.extend(Self::from_file(path)?);
+        Ok(deployments)
+    }
+
+    pub fn get(&self, chain_id: u64) -> Option<&Deployment> {
+        self.by_chain.get(&chain_id)
+    }
+
+    pub fn insert(&mut self, chain_id: u64, deployment: Deployment) -> Option<Deployment> {
+        self.by_chain.insert(chain_id, deployment)
+    }
+
+    /// Merges `other` into `self`, replacing entries for chains present in both.
+    pub fn extend(&mut self, other: Deployments) {
+        self.by_chain.extend(other.by_chain);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &Deployment)> {
+        self.by_chain.iter().map(|(chain_id, d)| (*chain_id, d))
+    }
+
+    pub fn to_json(&self) -> eyre::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_file(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        Ok(fs::write(path, self.to_json()?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_devnet() {
+        let deployments = Deployments::builtin();
+        let devnet = deployments.get(L1SLOAD_DEVNET_CHAIN_ID).unwrap();
+        assert_eq!(
+            devnet.l1_scroller,
+            address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC")
+        );
+        assert!(deployments.get(1).is_none());
+    }
+
+    #[test]
+    fn test_overrides() {
+        let json = r#"{
+            "2227728": { "l1Scroller": "0x59c6C9958b9c3603D0B753d90f370704e64D9311" },
+            "534351": {
+                "l1Scroller": "0x0000000000000000000000000000000000000001",
+                "erc20L1Scroller": "0x0000000000000000000000000000000000000002",
+                "block": 42
+            }
+        }"#;
+        let path = std::env::temp_dir().join(format!(
+            "scroller-rs-deployments-overrides-{}.json",
+            std::process::id()
+        ));
+        fs::write(&path, json).unwrap();
+        let deployments = Deployments::builtin_with_overrides(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(
+            deployments
+                .get(L1SLOAD_DEVNET_CHAIN_ID)
+                .unwrap()
+                .l1_scroller,
+            address!("59c6C9958b9c3603D0B753d90f370704e64D9311")
+        );
+        let sepolia = deployments.get(534351).unwrap();
+        assert_eq!(sepolia.erc20_l1scroller, Some(Address::with_last_byte(2)));
+        assert_eq!(sepolia.nft_l1scroller, None);
+        assert_eq!(sepolia.block, Some(42));
+
+        let roundtrip = Deployments::from_json(&deployments.to_json().unwrap()).unwrap();
+        assert_eq!(roundtrip, deployments);
+    }
+}
@@ -0,0 +1,365 @@
+use std::{
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use alloy::primitives::{Address, B256, U256};
+use redb::{Database, ReadableTable, ReadableTableMetadata, TableDefinition};
+
+use crate::cache::{CacheStats, SlotCache, SlotKey};
+
+/// `l1_contract ++ slot` to `l1_block (big endian) ++ value ++ immutable flag`.
+const SLOTS: TableDefinition<&[u8], &[u8]> = TableDefinition::new("slots");
+
+const KEY_LEN: usize = 20 + 32;
+const ENTRY_LEN: usize = 8 + 32 + 1;
+
+/// Latest observation of a slot stored by [`DiskSlotCache`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachedSlot {
+    pub l1_block: u64,
+    pub value: B256,
+    pub immutable: bool,
+}
+
+impl CachedSlot {
+    fn encode(&self) -> [u8; ENTRY_LEN] {
+        let mut entry = [0u8; ENTRY_LEN];
+        entry[..8].copy_from_slice(&self.l1_block.to_be_bytes());
+        entry[8..40].copy_from_slice(self.value.as_slice());
+        entry[40] = self.immutable as u8;
+        entry
+    }
+
+    fn decode(entry: &[u8]) -> Option<Self> {
+        if entry.len() != ENTRY_LEN {
+            return None;
+        }
+        Some(Self {
+            l1_block: u64::from_be_bytes(entry[..8].try_into().ok()?),
+            value: B256::from_slice(&entry[8..40]),
+            immutable: entry[40] != 0,
+        })
+    }
+}
+
+fn encode_key(l1_contract: Address, slot: U256) -> [u8; KEY_LEN] {
+    let mut key = [0u8; KEY_LEN];
+    key[..20].copy_from_slice(l1_contract.as_slice());
+    key[20..].copy_from_slice(&slot.to_be_bytes::<32>());
+    key
+}
+
+fn decode_key(key: &[u8]) -> (Address, U256) {
+    (
+        Address::from_slice(&key[..20]),
+        U256::from_be_slice(&key[20..]),
+    )
+}
+
+/// [`SlotCache`] persisted in a [redb](https://docs.rs/redb) database file, so reads
+/// survive restarts.
+///
+/// Only the latest observation of each slot is kept, together with the L1 block it was
+/// read at. A lookup for a newer L1 block than the stored one is served only while the
+/// entry is at most `max_l1_block_age` blocks old (0 by default, i.e. the exact block);
+/// older entries are dropped on lookup and the slot is read again. [`DiskSlotCache::stale`]
+/// lists the entries to revalidate in bulk after the L1 block advanced, e.g. with
+/// [`ReadOptions::bypass`](crate::cache::ReadOptions::bypass) reads.
+///
+/// Storage errors are counted in [`CacheStats::errors`] and treated as cache misses.
+#[derive(Debug)]
+pub struct DiskSlotCache {
+    db: Database,
+    max_l1_block_age: u64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    inserts: AtomicU64,
+    expirations: AtomicU64,
+    errors: AtomicU64,
+}
+
+impl DiskSlotCache {
+    /// Opens the cache at `path`, creating the file if needed.
+    pub fn open(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let db = Database::create(path)?;
+        let tx = db.begin_write()?;
+        tx.open_table(SLOTS)?;
+        tx.commit()?;
+        Ok(Self {
+            db,
+            max_l1_block_age: 0,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            inserts: AtomicU64::new(0),
+            expirations: AtomicU64::new(0),
+            errors: AtomicU64::new(0),
+        })
+    }
+
+    /// Serves entries read up to `blocks` L1 blocks before the requested one, as if they
+    /// had been read at it. Only for callers that tolerate values this stale.
+    pub fn with_max_l1_block_age(mut self, blocks: u64) -> Self {
+        self.max_l1_block_age = blocks;
+        self
+    }
+
+    /// Latest stored observation of the slot, whatever its block.
+    pub fn entry(&self, l1_contract: Address, slot: U256) -> eyre::Result<Option<CachedSlot>> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(SLOTS)?;
+        let entry = table.get(encode_key(l1_contract, slot).as_slice())?;
+        Ok(entry.and_then(|entry| CachedSlot::decode(entry.value())))
+    }
+
+    /// Slots whose stored value is too old to be served at `l1_block`.
+    pub fn stale(&self, l1_block: u64) -> eyre::Result<Vec<(Address, U256)>> {
+        let tx = self.db.begin_read()?;
+        let table = tx.open_table(SLOTS)?;
+        let mut stale = Vec::new();
+        for item in table.iter()? {
+            let (key, entry) = item?;
+            if CachedSlot::decode(entry.value()).is_some_and(|entry| !self.fresh(&entry, l1_block))
+            {
+                stale.push(decode_key(key.value()));
+            }
+        }
+        Ok(stale)
+    }
+
+    /// Removes every entry too old to be served at `l1_block`, returning how many.
+    pub fn prune(&self, l1_block: u64) -> eyre::Result<usize> {
+        let tx = self.db.begin_write()?;
+        let pruned = {
+            let mut table = tx.open_table(SLOTS)?;
+            let before = table.len()?;
+            table.retain(|_, entry| {
+                CachedSlot::decode(entry).is_some_and(|entry| self.fresh(&entry, l1_block))
+            })?;
+            before - table.len()?
+        };
+        tx.commit()?;
+        Ok(pruned as usize)
+    }
+
+    fn fresh(&self, entry: &CachedSlot, l1_block: u64) -> bool {
+        entry.immutable
+            || (entry.l1_block <= l1_block && l1_block - entry.l1_block <= self.max_l1_block_age)
+    }
+
+    fn lookup(&self, key: &SlotKey) -> eyre::Result<Option<B256>> {
+        let Some(entry) = self.entry(key.l1_contract, key.slot)? else {
+            return Ok(None);
+        };
+        if self.fresh(&entry, key.l1_block) {
+            return Ok(Some(entry.value));
+        }
+        if entry.l1_block < key.l1_block {
+            self.remove(key.l1_contract, key.slot)?;
+            self.expirations.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(None)
+    }
+
+    fn store(&self, l1_contract: Address, slot: U256, new: CachedSlot) -> eyre::Result<()> {
+        let key = encode_key(l1_contract, slot);
+        let tx = self.db.begin_write()?;
+        {
+            let mut table = tx.open_table(SLOTS)?;
+            let current = table
+                .get(key.as_slice())?
+                .and_then(|entry| CachedSlot::decode(entry.value()));
+            // Never replace an immutable value or a newer observation.
+            let keep = current.is_some_and(|current| {
+                !new.immutable && (current.immutable || current.l1_block > new.l1_block)
+            });
+            if !keep {
+                table.insert(key.as_slice(), new.encode().as_slice())?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn remove(&self, l1_contract: Address, slot: U256) -> eyre::Result<()> {
+        let tx = self.db.begin_write()?;
+        tx.open_table(SLOTS)?
+            .remove(encode_key(l1_contract, slot).as_slice())?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn record<T>(&self, result: eyre::Result<T>) -> Option<T> {
+        result
+            .inspect_err(|_| {
+                self.errors.fetch_add(1, Ordering::Relaxed);
+            })
+            .ok()
+    }
+
+    fn record_lookup(&self, value: Option<B256>) -> Option<B256> {
+        let counter = if value.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+}
+
+impl SlotCache for DiskSlotCache {
+    fn get(&self, key: &SlotKey) -> Option<B256> {
+        let value = self.record(self.lookup(key)).flatten();
+        self.record_lookup(value)
+    }
+
+    fn get_immutable(&self, l1_contract: Address, slot: U256) -> Option<B256> {
+        let entry = self.record(self.entry(l1_contract, slot)).flatten();
+        self.record_lookup(
+            entry
+                .filter(|entry| entry.immutable)
+                .map(|entry| entry.value),
+        )
+    }
+
+    fn insert(&self, key: SlotKey, value: B256) {
+        let entry = CachedSlot {
+            l1_block: key.l1_block,
+            value,
+            immutable: false,
+        };
+        if self
+            .record(self.store(key.l1_contract, key.slot, entry))
+            .is_some()
+        {
+            self.inserts.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn insert_immutable(&self, l1_contract: Address, slot: U256, value: B256) {
+        let entry = CachedSlot {
+            l1_block: 0,
+            value,
+            immutable: true,
+        };
+        if self.record(self.store(l1_contract, slot, entry)).is_some() {
+            self.inserts.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn invalidate(&self, l1_contract: Address, slot: U256) {
+        self.record(self.remove(l1_contract, slot));
+    }
+
+    fn clear(&self) {
+        let result = (|| -> eyre::Result<()> {
+            let tx = self.db.begin_write()?;
+            tx.delete_table(SLOTS)?;
+            tx.open_table(SLOTS)?;
+            tx.commit()?;
+            Ok(())
+        })();
+        self.record(result);
+    }
+
+    fn expire_before(&self, l1_block: u64) {
+        if let Some(pruned) = self.record(self.prune(l1_block)) {
+            self.expirations.fetch_add(pruned as u64, Ordering::Relaxed);
+        }
+    }
+
+    fn stats(&self) -> CacheStats {
+        let entries =
+            (|| -> eyre::Result<u64> { Ok(self.db.begin_read()?.open_table(SLOTS)?.len()?) })();
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            inserts: self.inserts.load(Ordering::Relaxed),
+            evictions: 0,
+            expirations: self.expirations.load(Ordering::Relaxed),
+            errors: self.errors.load(Ordering::Relaxed),
+            entries: self.record(entries).unwrap_or_default() as usize,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+
+    const TOKEN: Address = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("{name}-{}.redb", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn test_reopen_hits_exact_block_by_default() {
+        let path = temp_path("scroller-cache-reopen");
+        let key = SlotKey::new(TOKEN, U256::from(2), 100);
+        DiskSlotCache::open(&path)
+            .unwrap()
+            .insert(key, B256::with_last_byte(1));
+
+        let cache = DiskSlotCache::open(&path).unwrap();
+        assert_eq!(cache.get(&key), Some(B256::with_last_byte(1)));
+        // The L1 block moved on: the entry is not served for it.
+        let later = SlotKey::new(TOKEN, key.slot, 101);
+        assert_eq!(cache.get(&later), None);
+        assert_eq!(cache.entry(TOKEN, key.slot).unwrap(), None);
+
+        // Unless stale entries are opted into.
+        cache.insert(key, B256::with_last_byte(1));
+        drop(cache);
+        let cache = DiskSlotCache::open(&path)
+            .unwrap()
+            .with_max_l1_block_age(25);
+        assert_eq!(
+            cache.get(&SlotKey::new(TOKEN, key.slot, 125)),
+            Some(B256::with_last_byte(1))
+        );
+        assert_eq!(cache.get(&SlotKey::new(TOKEN, key.slot, 126)), None);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_survives_reopen_and_expires_by_block() {
+        let path = temp_path("scroller-cache-expiry");
+
+        let slot = U256::from(2);
+        {
+            let cache = DiskSlotCache::open(&path).unwrap();
+            cache.insert(SlotKey::new(TOKEN, slot, 100), B256::with_last_byte(1));
+            cache.insert_immutable(TOKEN, U256::from(3), B256::with_last_byte(0x0a));
+        }
+
+        let cache = DiskSlotCache::open(&path).unwrap().with_max_l1_block_age(2);
+        assert_eq!(
+            cache.get(&SlotKey::new(TOKEN, slot, 102)),
+            Some(B256::with_last_byte(1))
+        );
+        assert_eq!(cache.get(&SlotKey::new(TOKEN, slot, 99)), None);
+        assert_eq!(cache.stale(103).unwrap(), vec![(TOKEN, slot)]);
+        assert_eq!(cache.get(&SlotKey::new(TOKEN, slot, 103)), None);
+        assert_eq!(cache.entry(TOKEN, slot).unwrap(), None);
+        assert_eq!(
+            cache.get_immutable(TOKEN, U256::from(3)),
+            Some(B256::with_last_byte(0x0a))
+        );
+
+        cache.insert(SlotKey::new(TOKEN, slot, 110), B256::with_last_byte(2));
+        cache.insert(SlotKey::new(TOKEN, slot, 105), B256::with_last_byte(9));
+        assert_eq!(cache.entry(TOKEN, slot).unwrap().unwrap().l1_block, 110);
+        assert_eq!(cache.prune(200).unwrap(), 1);
+
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.expirations), (2, 2, 1));
+        assert_eq!((stats.entries, stats.errors), (1, 0));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
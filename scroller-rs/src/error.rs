@@ -1,5 +1,8 @@
+use alloy::primitives::{Address, TxHash};
 use thiserror::Error;
 
+use crate::{precompile::PrecompileError, storage::StorageError};
+
 #[derive(Debug, Error)]
 pub enum L1ScrollerError {
     #[error("Failed to read slot")]
@@ -37,4 +40,72 @@ pub enum L1ScrollerError {
 
     #[error("Failed to read String")]
     ReadStringError,
+
+    #[error("No L1Scroller address is set, only the L1SLOAD precompile can be read")]
+    NoL1Scroller,
+
+    #[error("Failed to read chain id")]
+    ChainIdError,
+
+    #[error("No L1Scroller deployment known for chain id {0}")]
+    UnknownDeployment(u64),
+
+    #[error("Deployment transaction {0} did not create a contract")]
+    DeploymentReverted(TxHash),
+
+    #[error("Runtime bytecode at {0} does not match the compiled contract")]
+    BytecodeMismatch(Address),
+
+    #[error("Failed to call the L1SLOAD precompile")]
+    PrecompileCallError,
+
+    #[error("Failed to read the latest L1 block from L1Blocks")]
+    L1BlockError,
+
+    #[error("Failed to read the latest L2 block number")]
+    BlockNumberError,
+
+    #[error("Failed to read storage from L1")]
+    L1StorageError,
+
+    #[error("Invalid storage layout: {0}")]
+    Layout(String),
+
+    #[error(transparent)]
+    Precompile(#[from] PrecompileError),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+}
+
+impl L1ScrollerError {
+    /// Short snake_case name of the variant, used as the `error` label of metrics.
+    pub fn class(&self) -> &'static str {
+        match self {
+            L1ScrollerError::ReadSlotError => "read_slot",
+            L1ScrollerError::ReadMultipleSlotError => "read_multiple_slots",
+            L1ScrollerError::ReadUintError
+            | L1ScrollerError::ReadU160Error
+            | L1ScrollerError::ReadAddressError
+            | L1ScrollerError::ReadU96Error
+            | L1ScrollerError::ReadU48Error
+            | L1ScrollerError::ReadU24Error
+            | L1ScrollerError::ReadU8Error
+            | L1ScrollerError::ReadU32Error
+            | L1ScrollerError::ReadU64Error
+            | L1ScrollerError::ReadStringError => "read_typed",
+            L1ScrollerError::NoL1Scroller => "no_l1_scroller",
+            L1ScrollerError::ChainIdError => "chain_id",
+            L1ScrollerError::UnknownDeployment(_) => "unknown_deployment",
+            L1ScrollerError::DeploymentReverted(_) => "deployment_reverted",
+            L1ScrollerError::BytecodeMismatch(_) => "bytecode_mismatch",
+            L1ScrollerError::PrecompileCallError => "precompile_call",
+            L1ScrollerError::L1BlockError => "l1_block",
+            L1ScrollerError::BlockNumberError => "block_number",
+            L1ScrollerError::L1StorageError => "l1_storage",
+            L1ScrollerError::Layout(_) => "layout",
+            L1ScrollerError::Precompile(_) => "precompile_encoding",
+            L1ScrollerError::Storage(_) => "storage",
+        }
+    }
 }
@@ -0,0 +1,538 @@
+use alloy::{
+    consensus::{SignableTransaction, TxEip1559},
+    network::{Ethereum, TransactionBuilder},
+    primitives::{address, Address, Bytes, TxKind, U256},
+    providers::{
+        fillers::{FillerControlFlow, TxFiller},
+        PendingTransactionBuilder, Provider, SendableTx,
+    },
+    rpc::types::TransactionRequest,
+    sol,
+    sol_types::{SolCall, SolInterface},
+    transports::{RpcError, Transport, TransportError, TransportResult},
+};
+
+use crate::{
+    bindings::l1scroller::L1Scroller::L1ScrollerCalls, precompile::L1SLOAD_ADDRESS, telemetry,
+};
+
+/// Address of Scroll's `L1GasPriceOracle` predeploy.
+pub const L1_GAS_PRICE_ORACLE_ADDRESS: Address =
+    address!("5300000000000000000000000000000000000002");
+
+/// Fixed gas charged by the L1SLOAD precompile for every call, `L1SLOAD_FIXED_GAS_COST`
+/// of [RIP-7728](https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7728.md).
+pub const L1SLOAD_FIXED_GAS_COST: u64 = 2000;
+
+/// Gas charged by the L1SLOAD precompile for every slot read, `L1SLOAD_PER_LOAD_GAS_COST`
+/// of RIP-7728.
+pub const L1SLOAD_PER_LOAD_GAS_COST: u64 = 2000;
+
+/// Smallest fee increase nodes accept for a replacement transaction.
+pub const MIN_REPLACEMENT_BUMP_PERCENT: u128 = 10;
+
+sol! {
+    interface IL1GasPriceOracle {
+        function getL1Fee(bytes memory data) external view returns (uint256);
+    }
+}
+
+/// Gas charged by the L1SLOAD precompile for a call reading `slots` slots.
+pub fn l1sload_gas(slots: usize) -> u64 {
+    L1SLOAD_FIXED_GAS_COST + L1SLOAD_PER_LOAD_GAS_COST * slots as u64
+}
+
+/// Gas and fees chosen by [`ScrollGasFiller`] for a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScrollFees {
+    pub gas_limit: u64,
+    pub max_fee_per_gas: u128,
+    pub max_priority_fee_per_gas: u128,
+    /// Fee charged by Scroll for posting the transaction data to L1, on top of the L2 gas.
+    pub l1_data_fee: U256,
+}
+
+impl ScrollFees {
+    /// Highest amount the sender pays for the transaction, excluding `value`.
+    pub fn max_cost(&self) -> U256 {
+        U256::from(self.gas_limit) * U256::from(self.max_fee_per_gas) + self.l1_data_fee
+    }
+}
+
+/// Gas and fee filler for Scroll.
+///
+/// On top of `eth_estimateGas` it adds the L1SLOAD precompile cost for calls it can
+/// attribute slots to (direct precompile calls and calls to a known L1Scroller), applies
+/// a safety margin, enforces fee floors and checks that the sender can also pay the L1
+/// data fee quoted by the `L1GasPriceOracle`.
+///
+/// Use it instead of the recommended fillers:
+///
+/// ```ignore
+/// let provider = ProviderBuilder::new()
+///     .filler(ScrollGasFiller::default().with_scroller(scroller))
+///     .filler(NonceFiller::default())
+///     .filler(ChainIdFiller::default())
+///     .wallet(wallet)
+///     .on_http(url);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ScrollGasFiller {
+    /// L1Scroller deployments whose calldata is decoded to count the slots read.
+    pub scrollers: Vec<Address>,
+    /// Percentage added to the estimated gas.
+    pub gas_margin_percent: u64,
+    /// Gas limit used when `eth_estimateGas` fails.
+    pub fallback_gas_limit: Option<u64>,
+    pub min_max_fee_per_gas: u128,
+    pub min_priority_fee_per_gas: u128,
+    /// Fail early when `from` cannot pay the gas, `value` and the L1 data fee.
+    pub check_balance: bool,
+}
+
+impl Default for ScrollGasFiller {
+    fn default() -> Self {
+        Self {
+            scrollers: Vec::new(),
+            gas_margin_percent: 20,
+            fallback_gas_limit: None,
+            min_max_fee_per_gas: 0,
+            min_priority_fee_per_gas: 0,
+            check_balance: true,
+        }
+    }
+}
+
+impl ScrollGasFiller {
+    pub fn with_scroller(mut self, scroller: Address) -> Self {
+        self.scrollers.push(scroller);
+        self
+    }
+
+    /// Number of L1SLOAD slots `tx` reads, as far as it can be told from its calldata.
+    pub fn l1sload_slots(&self, tx: &TransactionRequest) -> usize {
+        let (Some(TxKind::Call(to)), Some(input)) = (tx.to, tx.input.input()) else {
+            return 0;
+        };
+        if to == L1SLOAD_ADDRESS {
+            return input.len().saturating_sub(Address::len_bytes()) / 32;
+        }
+        if !self.scrollers.contains(&to) {
+            return 0;
+        }
+        match L1ScrollerCalls::abi_decode(input, false) {
+            Ok(L1ScrollerCalls::readMultipleSlots(call)) => call.slot.len(),
+            Ok(L1ScrollerCalls::bytesToUint(_)) | Ok(L1ScrollerCalls::bytes32ToString(_)) => 0,
+            Ok(_) => 1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Gas limit for a transaction estimated at `estimate` that reads `slots` slots.
+    pub fn gas_limit(&self, estimate: u64, slots: usize) -> u64 {
+        let gas = if slots == 0 {
+            estimate
+        } else {
+            estimate + l1sload_gas(slots)
+        };
+        gas + gas * self.gas_margin_percent / 100
+    }
+
+    /// Computes the gas limit, fees and L1 data fee for `tx`, keeping any values it already has.
+    pub async fn estimate<T, P>(
+        &self,
+        provider: &P,
+        tx: &TransactionRequest,
+    ) -> TransportResult<ScrollFees>
+    where
+        T: Transport + Clone,
+        P: Provider<T>,
+    {
+        let gas_limit = match tx.gas {
+            Some(gas_limit) => gas_limit,
+            None => match (provider.estimate_gas(tx).await, self.fallback_gas_limit) {
+                (Ok(estimate), _) => self.gas_limit(estimate, self.l1sload_slots(tx)),
+                (Err(_), Some(fallback)) => fallback,
+                (Err(err), None) => return Err(err),
+            },
+        };
+
+        let (max_fee_per_gas, max_priority_fee_per_gas) =
+            match (tx.max_fee_per_gas, tx.max_priority_fee_per_gas) {
+                (Some(max_fee), Some(priority_fee)) => (max_fee, priority_fee),
+                _ => {
+                    let estimate = provider.estimate_eip1559_fees(None).await?;
+                    let priority_fee = estimate
+                        .max_priority_fee_per_gas
+                        .max(self.min_priority_fee_per_gas);
+                    let max_fee = estimate
+                        .max_fee_per_gas
+                        .max(self.min_max_fee_per_gas)
+                        .max(priority_fee);
+                    (max_fee, priority_fee)
+                }
+            };
+
+        let unsigned = TxEip1559 {
+            chain_id: tx.chain_id.unwrap_or_default(),
+            nonce: tx.nonce.unwrap_or_default(),
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            to: tx.to.unwrap_or(TxKind::Create),
+            value: tx.value.unwrap_or_default(),
+            access_list: tx.access_list.clone().unwrap_or_default(),
+            input: tx.input.input().cloned().unwrap_or_default(),
+        };
+        let l1_data_fee = l1_data_fee(provider, unsigned.encoded_for_signing().into()).await?;
+
+        Ok(ScrollFees {
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            l1_data_fee,
+        })
+    }
+}
+
+impl TxFiller<Ethereum> for ScrollGasFiller {
+    type Fillable = ScrollFees;
+
+    fn status(&self, tx: &TransactionRequest) -> FillerControlFlow {
+        if tx.gas.is_some()
+            && (tx.gas_price.is_some()
+                || (tx.max_fee_per_gas.is_some() && tx.max_priority_fee_per_gas.is_some()))
+        {
+            return FillerControlFlow::Finished;
+        }
+        FillerControlFlow::Ready
+    }
+
+    fn fill_sync(&self, _tx: &mut SendableTx<Ethereum>) {}
+
+    async fn prepare<P, T>(
+        &self,
+        provider: &P,
+        tx: &TransactionRequest,
+    ) -> TransportResult<Self::Fillable>
+    where
+        P: Provider<T, Ethereum>,
+        T: Transport + Clone,
+    {
+        let fees = self.estimate(provider, tx).await?;
+        if let (true, Some(from)) = (self.check_balance, tx.from) {
+            let balance = provider.get_balance(from).await?;
+            if balance < fees.max_cost() + tx.value.unwrap_or_default() {
+                return Err(RpcError::local_usage_str(
+                    "insufficient funds for gas * price + value + L1 data fee",
+                ));
+            }
+        }
+        Ok(fees)
+    }
+
+    async fn fill(
+        &self,
+        fillable: Self::Fillable,
+        mut tx: SendableTx<Ethereum>,
+    ) -> TransportResult<SendableTx<Ethereum>> {
+        if let Some(builder) = tx.as_mut_builder() {
+            builder.set_gas_limit(fillable.gas_limit);
+            builder.set_max_fee_per_gas(fillable.max_fee_per_gas);
+            builder.set_max_priority_fee_per_gas(fillable.max_priority_fee_per_gas);
+        }
+        Ok(tx)
+    }
+}
+
+/// L1 data fee quoted by the `L1GasPriceOracle` for the RLP encoded unsigned transaction `data`.
+pub async fn l1_data_fee<T, P>(provider: &P, data: Bytes) -> TransportResult<U256>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let call = IL1GasPriceOracle::getL1FeeCall { data };
+    let tx = TransactionRequest::default()
+        .with_to(L1_GAS_PRICE_ORACLE_ADDRESS)
+        .with_input(call.abi_encode());
+    let out = provider.call(&tx).await?;
+    let fee = IL1GasPriceOracle::getL1FeeCall::abi_decode_returns(&out, true)
+        .map_err(TransportError::local_usage)?;
+    Ok(fee._0)
+}
+
+/// Sends `tx`, raising its fees by `bump_percent` (at least
+/// [`MIN_REPLACEMENT_BUMP_PERCENT`]) each time the node rejects it as underpriced.
+///
+/// The nonce and fees are pinned before the first attempt so that every retry
+/// replaces the same transaction.
+pub async fn send_with_fee_bump<T, P>(
+    provider: &P,
+    mut tx: TransactionRequest,
+    bump_percent: u128,
+    max_bumps: u32,
+) -> TransportResult<PendingTransactionBuilder<T, Ethereum>>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    if let (None, Some(from)) = (tx.nonce, tx.from) {
+        tx.nonce = Some(provider.get_transaction_count(from).pending().await?);
+    }
+    if tx.gas_price.is_none()
+        && (tx.max_fee_per_gas.is_none() || tx.max_priority_fee_per_gas.is_none())
+    {
+        let estimate = provider.estimate_eip1559_fees(None).await?;
+        let priority_fee = tx
+            .max_priority_fee_per_gas
+            .unwrap_or(estimate.max_priority_fee_per_gas);
+        let max_fee = tx
+            .max_fee_per_gas
+            .unwrap_or(estimate.max_fee_per_gas)
+            .max(priority_fee);
+        tx.max_fee_per_gas = Some(max_fee);
+        tx.max_priority_fee_per_gas = Some(priority_fee);
+    }
+
+    let bump_percent = bump_percent.max(MIN_REPLACEMENT_BUMP_PERCENT);
+    let mut bumps = 0;
+    loop {
+        match provider.send_transaction(tx.clone()).await {
+            Ok(pending) => return Ok(pending),
+            Err(err) if is_underpriced(&err) && bumps < max_bumps => {
+                bumps += 1;
+                telemetry::fee_bump_retry(bumps);
+                if let Some(gas_price) = tx.gas_price {
+                    tx.gas_price = Some(bump_fee(gas_price, bump_percent));
+                } else {
+                    let max_fee = tx.max_fee_per_gas.unwrap_or_default();
+                    let priority_fee = tx.max_priority_fee_per_gas.unwrap_or_default();
+                    tx.max_fee_per_gas = Some(bump_fee(max_fee, bump_percent));
+                    tx.max_priority_fee_per_gas = Some(bump_fee(priority_fee, bump_percent));
+                }
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Whether `err` is the node rejecting a transaction for paying too little, e.g.
+/// "replacement transaction underpriced".
+pub fn is_underpriced(err: &TransportError) -> bool {
+    err.to_string().contains("underpriced")
+}
+
+/// Raises `fee` by `percent`, by at least one wei.
+pub fn bump_fee(fee: u128, percent: u128) -> u128 {
+    (fee + fee * percent / 100).max(fee + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+    use crate::bindings::l1scroller::L1Scroller;
+
+    const SCROLLER: Address = address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+
+    #[test]
+    fn test_l1sload_slots() {
+        let filler = ScrollGasFiller::default().with_scroller(SCROLLER);
+        let l1_contract = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+
+        let read_multiple = L1Scroller::readMultipleSlotsCall {
+            l1_contract,
+            slot: vec![U256::from(0), U256::from(1), U256::from(2)],
+        };
+        let tx = TransactionRequest::default()
+            .with_to(SCROLLER)
+            .with_input(read_multiple.abi_encode());
+        assert_eq!(filler.l1sload_slots(&tx), 3);
+
+        let read_uint = L1Scroller::readUintCall {
+            l1_contract,
+            slot: U256::from(0),
+        };
+        let tx = TransactionRequest::default()
+            .with_to(SCROLLER)
+            .with_input(read_uint.abi_encode());
+        assert_eq!(filler.l1sload_slots(&tx), 1);
+        assert_eq!(
+            ScrollGasFiller::default().l1sload_slots(&tx),
+            0,
+            "unknown scroller"
+        );
+
+        let mut raw = l1_contract.to_vec();
+        raw.extend_from_slice(&[0u8; 64]);
+        let tx = TransactionRequest::default()
+            .with_to(L1SLOAD_ADDRESS)
+            .with_input(raw);
+        assert_eq!(filler.l1sload_slots(&tx), 2);
+    }
+
+    #[test]
+    fn test_gas_limit() {
+        let filler = ScrollGasFiller::default();
+        assert_eq!(filler.gas_limit(30_000, 0), 36_000);
+        assert_eq!(filler.gas_limit(30_000, 2), 43_200);
+        assert_eq!(l1sload_gas(5), 12_000);
+    }
+
+    /// Answers of a mock node quoting a 1 gwei base fee, a 0.1 gwei tip and a 1000 wei L1
+    /// data fee, and estimating every transaction at 30000 gas.
+    #[cfg(feature = "http")]
+    fn node_response(
+        method: &str,
+        balance: u64,
+    ) -> Result<serde_json::Value, alloy::rpc::json_rpc::ErrorPayload> {
+        use alloy::rpc::json_rpc::ErrorPayload;
+        use serde_json::json;
+
+        match method {
+            "eth_estimateGas" => Ok(json!("0x7530")),
+            "eth_feeHistory" => Ok(json!({
+                "oldestBlock": "0x1",
+                "baseFeePerGas": ["0x3b9aca00", "0x3b9aca00"],
+                "gasUsedRatio": [0.5],
+                "reward": [["0x5f5e100"]],
+            })),
+            "eth_call" => Ok(json!(Bytes::from(U256::from(1000).to_be_bytes_vec()))),
+            "eth_getBalance" => Ok(json!(U256::from(balance))),
+            "eth_getTransactionCount" => Ok(json!("0x7")),
+            _ => Err(ErrorPayload::method_not_found()),
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn mock_node(balance: u64) -> crate::mock::MockTransport {
+        crate::mock::MockTransport::new(move |method, _| node_response(method, balance))
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_prepare_and_fill() {
+        use alloy::providers::ProviderBuilder;
+
+        let from = Address::with_last_byte(1);
+        let call = L1Scroller::readMultipleSlotsCall {
+            l1_contract: Address::ZERO,
+            slot: vec![U256::from(0), U256::from(1)],
+        };
+        let tx = TransactionRequest::default()
+            .with_from(from)
+            .with_to(SCROLLER)
+            .with_input(call.abi_encode());
+        let filler = ScrollGasFiller::default().with_scroller(SCROLLER);
+        assert!(filler.status(&tx).is_ready());
+
+        let transport = mock_node(1_000_000_000_000_000);
+        let provider = ProviderBuilder::new().on_client(transport.client());
+        let fees = filler.prepare(&provider, &tx).await.unwrap();
+        assert_eq!(
+            fees,
+            ScrollFees {
+                gas_limit: 43_200,
+                max_fee_per_gas: 2_100_000_000,
+                max_priority_fee_per_gas: 100_000_000,
+                l1_data_fee: U256::from(1000),
+            }
+        );
+        let oracle_calls = transport.params("eth_call");
+        assert_eq!(oracle_calls.len(), 1);
+        assert_eq!(
+            oracle_calls[0][0]["to"]
+                .as_str()
+                .unwrap()
+                .parse::<Address>()
+                .unwrap(),
+            L1_GAS_PRICE_ORACLE_ADDRESS
+        );
+        assert_eq!(transport.params("eth_getBalance").len(), 1);
+
+        let tx = filler.fill(fees, SendableTx::Builder(tx)).await.unwrap();
+        let tx = tx.as_builder().unwrap();
+        assert_eq!(tx.gas, Some(43_200));
+        assert_eq!(tx.max_fee_per_gas, Some(2_100_000_000));
+        assert_eq!(tx.max_priority_fee_per_gas, Some(100_000_000));
+        assert!(filler.status(tx).is_finished());
+
+        // 43200 gas at 2.1 gwei plus the L1 data fee is more than the balance.
+        let transport = mock_node(90_000_000_000_000);
+        let provider = ProviderBuilder::new().on_client(transport.client());
+        let err = filler.prepare(&provider, &tx.clone()).await.unwrap_err();
+        assert!(err.to_string().contains("insufficient funds"), "{err}");
+    }
+
+    #[cfg(feature = "http")]
+    #[tokio::test]
+    async fn test_send_with_fee_bump() {
+        use std::sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        };
+
+        use alloy::{primitives::B256, providers::ProviderBuilder, rpc::json_rpc::ErrorPayload};
+        use serde_json::json;
+
+        // Rejects the first three sends as underpriced.
+        let sends = Arc::new(AtomicU32::new(0));
+        let counter = sends.clone();
+        let transport = crate::mock::MockTransport::new(move |method, _| match method {
+            "eth_sendTransaction" if counter.fetch_add(1, Ordering::SeqCst) < 3 => {
+                Err(ErrorPayload {
+                    code: -32000,
+                    message: "replacement transaction underpriced".into(),
+                    data: None,
+                })
+            }
+            "eth_sendTransaction" => Ok(json!(B256::with_last_byte(1))),
+            _ => node_response(method, 0),
+        });
+        let provider = ProviderBuilder::new().on_client(transport.client());
+        let tx = TransactionRequest::default()
+            .with_from(Address::with_last_byte(1))
+            .with_to(SCROLLER)
+            .with_gas_limit(50_000);
+
+        // One bump short of getting through.
+        let err = send_with_fee_bump(&provider, tx.clone(), 20, 2)
+            .await
+            .unwrap_err();
+        assert!(is_underpriced(&err), "{err}");
+        assert_eq!(sends.load(Ordering::SeqCst), 3);
+
+        sends.store(0, Ordering::SeqCst);
+        let pending = send_with_fee_bump(&provider, tx, 20, 3).await.unwrap();
+        assert_eq!(*pending.tx_hash(), B256::with_last_byte(1));
+
+        let sent = transport.params("eth_sendTransaction");
+        let fees: Vec<_> = sent[3..]
+            .iter()
+            .map(|params| {
+                assert_eq!(params[0]["nonce"], json!("0x7"));
+                let fee = |field: &str| params[0][field].as_str().unwrap().to_owned();
+                (fee("maxFeePerGas"), fee("maxPriorityFeePerGas"))
+            })
+            .collect();
+        let expected = [
+            (2_100_000_000u128, 100_000_000u128),
+            (2_520_000_000, 120_000_000),
+            (3_024_000_000, 144_000_000),
+            (3_628_800_000, 172_800_000),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|(max_fee, priority_fee)| (format!("{max_fee:#x}"), format!("{priority_fee:#x}")))
+            .collect();
+        assert_eq!(fees, expected);
+    }
+
+    #[test]
+    fn test_bump_fee() {
+        assert_eq!(bump_fee(551_000_000, 20), 661_200_000);
+        assert_eq!(bump_fee(0, 20), 1);
+        assert_eq!(bump_fee(3, 10), 4);
+    }
+}
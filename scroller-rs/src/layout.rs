@@ -0,0 +1,626 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    path::Path,
+    str::FromStr,
+};
+
+#[cfg(feature = "transport")]
+use alloy::rpc::types::BlockId;
+use alloy::{
+    hex,
+    primitives::{keccak256, Address, Bytes, B256, I256, U256},
+};
+use serde::{Deserialize, Deserializer, Serialize};
+
+#[cfg(feature = "transport")]
+use crate::l1scroller::{L1ScrollerContracttype, Scroller};
+use crate::{
+    error::L1ScrollerError,
+    storage::{
+        data_slot, decode_word, mapping_slot, DecodedValue, StorageError, StringSlot, ValueType,
+    },
+};
+
+/// Elements of a fixed-size array expanded by [`StorageLayout::variables`]; longer arrays
+/// are truncated.
+pub const MAX_ARRAY_ELEMENTS: usize = 256;
+
+/// Most data slots of long strings and bytes read by [`collect_words`], across all
+/// variables.
+pub const MAX_DATA_SLOTS: usize = 1 << 16;
+
+/// `storageLayout` output of solc, as written by `forge inspect <Contract> storageLayout`
+/// or into forge artifacts with `extra_output = ["storageLayout"]`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct StorageLayout {
+    pub storage: Vec<StorageEntry>,
+    #[serde(default)]
+    pub types: BTreeMap<String, TypeInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StorageEntry {
+    pub label: String,
+    pub offset: usize,
+    #[serde(deserialize_with = "from_str")]
+    pub slot: U256,
+    #[serde(rename = "type")]
+    pub type_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeInfo {
+    /// `inplace`, `mapping`, `dynamic_array` or `bytes`.
+    pub encoding: String,
+    pub label: String,
+    #[serde(deserialize_with = "from_str")]
+    pub number_of_bytes: usize,
+    pub key: Option<String>,
+    pub value: Option<String>,
+    pub base: Option<String>,
+    pub members: Option<Vec<StorageEntry>>,
+}
+
+fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+/// Keys to expand a mapping (or indices of a dynamic array) with, e.g.
+/// `balances=0xA8E5...` or `allowances=<owner>,<spender>` for nested mappings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingKey {
+    pub variable: String,
+    pub keys: Vec<String>,
+}
+
+impl FromStr for MappingKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (variable, keys) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected `<variable>=<key>[,<key>...]`, got `{s}`"))?;
+        Ok(Self {
+            variable: variable.to_string(),
+            keys: keys.split(',').map(|key| key.trim().to_string()).collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VariableKind {
+    Value(ValueType),
+    String,
+    Bytes,
+    /// Length of a dynamic array.
+    Length,
+}
+
+/// A value stored in a single slot, found by expanding a [`StorageLayout`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutVariable {
+    /// Path of the value, e.g. `config.admin`, `balances[0xA8E5...]` or `flags[2]`.
+    pub name: String,
+    pub type_label: String,
+    pub slot: U256,
+    pub offset: usize,
+    pub kind: VariableKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutValue {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_label: String,
+    pub slot: U256,
+    pub offset: usize,
+    pub value: DecodedValue,
+}
+
+impl StorageLayout {
+    /// Parses a storage layout, either on its own or inside a forge artifact.
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        let mut value: serde_json::Value = serde_json::from_str(json)?;
+        if let Some(layout) = value.get_mut("storageLayout") {
+            value = layout.take();
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Every value of the top-level variables: structs and fixed-size arrays are expanded
+    /// member by member, dynamic arrays give their length, and mappings and array elements
+    /// are only expanded for the given `keys`.
+    pub fn variables(
+        &self,
+        keys: &[MappingKey],
+    ) -> eyre::Result<Vec<LayoutVariable>, L1ScrollerError> {
+        let mut variables = Vec::new();
+        for entry in &self.storage {
+            self.expand(
+                &entry.label,
+                &entry.type_id,
+                entry.slot,
+                entry.offset,
+                &[],
+                keys,
+                &mut variables,
+            )?;
+        }
+        Ok(variables)
+    }
+
+    fn type_info(&self, type_id: &str) -> eyre::Result<&TypeInfo, L1ScrollerError> {
+        self.types
+            .get(type_id)
+            .ok_or_else(|| L1ScrollerError::Layout(format!("unknown type `{type_id}`")))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn expand(
+        &self,
+        name: &str,
+        type_id: &str,
+        slot: U256,
+        offset: usize,
+        pending: &[String],
+        keys: &[MappingKey],
+        out: &mut Vec<LayoutVariable>,
+    ) -> eyre::Result<(), L1ScrollerError> {
+        let info = self.type_info(type_id)?;
+        let key_lists: Vec<&[String]> = if pending.is_empty() {
+            keys.iter()
+                .filter(|key| key.variable == name)
+                .map(|key| key.keys.as_slice())
+                .collect()
+        } else {
+            vec![pending]
+        };
+
+        match info.encoding.as_str() {
+            "bytes" => out.push(LayoutVariable {
+                name: name.to_string(),
+                type_label: info.label.clone(),
+                slot,
+                offset: 0,
+                kind: if info.label == "string" {
+                    VariableKind::String
+                } else {
+                    VariableKind::Bytes
+                },
+            }),
+            "mapping" => {
+                let (Some(key_type), Some(value_type)) = (&info.key, &info.value) else {
+                    return Err(L1ScrollerError::Layout(format!(
+                        "mapping `{type_id}` without key or value type"
+                    )));
+                };
+                for list in key_lists {
+                    let Some((key, rest)) = list.split_first() else {
+                        continue;
+                    };
+                    let key_slot =
+                        mapping_slot_for(self.type_info(key_type)?, key_type, key, slot)?;
+                    let child = format!("{name}[{key}]");
+                    self.expand(&child, value_type, key_slot, 0, rest, keys, out)?;
+                }
+            }
+            "dynamic_array" => {
+                out.push(LayoutVariable {
+                    name: format!("{name}.length"),
+                    type_label: "uint256".to_string(),
+                    slot,
+                    offset: 0,
+                    kind: VariableKind::Length,
+                });
+                let base = base_type(info, type_id)?;
+                let base_info = self.type_info(base)?;
+                for list in key_lists {
+                    let Some((index, rest)) = list.split_first() else {
+                        continue;
+                    };
+                    let i: usize = index.parse().map_err(|_| {
+                        L1ScrollerError::Layout(format!("invalid index `{index}` for `{name}`"))
+                    })?;
+                    let (element_slot, element_offset) =
+                        element_position(data_slot(slot), base_info.number_of_bytes, i);
+                    let child = format!("{name}[{i}]");
+                    self.expand(&child, base, element_slot, element_offset, rest, keys, out)?;
+                }
+            }
+            _ if info.members.is_some() => {
+                for member in info.members.iter().flatten() {
+                    let child = format!("{name}.{}", member.label);
+                    self.expand(
+                        &child,
+                        &member.type_id,
+                        slot + member.slot,
+                        member.offset,
+                        &[],
+                        keys,
+                        out,
+                    )?;
+                }
+            }
+            _ if info.base.is_some() => {
+                let base = base_type(info, type_id)?;
+                let base_info = self.type_info(base)?;
+                let len = fixed_array_len(type_id).ok_or_else(|| {
+                    L1ScrollerError::Layout(format!("cannot find the length of `{type_id}`"))
+                })?;
+                for i in 0..len.min(MAX_ARRAY_ELEMENTS) {
+                    let (element_slot, element_offset) =
+                        element_position(slot, base_info.number_of_bytes, i);
+                    let child = format!("{name}[{i}]");
+                    self.expand(&child, base, element_slot, element_offset, &[], keys, out)?;
+                }
+            }
+            _ => out.push(LayoutVariable {
+                name: name.to_string(),
+                type_label: info.label.clone(),
+                slot,
+                offset,
+                kind: VariableKind::Value(value_type(type_id, info)),
+            }),
+        }
+        Ok(())
+    }
+}
+
+fn base_type<'a>(info: &'a TypeInfo, type_id: &str) -> eyre::Result<&'a str, L1ScrollerError> {
+    info.base
+        .as_deref()
+        .ok_or_else(|| L1ScrollerError::Layout(format!("array `{type_id}` without base type")))
+}
+
+/// Length of a fixed-size array type such as `t_array(t_uint8)4_storage`.
+fn fixed_array_len(type_id: &str) -> Option<usize> {
+    let (_, len) = type_id.rsplit_once(')')?;
+    len.strip_suffix("_storage").unwrap_or(len).parse().ok()
+}
+
+/// Slot and offset of element `i` of an array starting at `first`, packing elements
+/// smaller than a slot the way solc does.
+fn element_position(first: U256, element_bytes: usize, i: usize) -> (U256, usize) {
+    if element_bytes == 0 || element_bytes > 16 {
+        let slots = element_bytes.div_ceil(32).max(1);
+        return (first + U256::from(i * slots), 0);
+    }
+    let per_slot = 32 / element_bytes;
+    (
+        first + U256::from(i / per_slot),
+        (i % per_slot) * element_bytes,
+    )
+}
+
+fn value_type(type_id: &str, info: &TypeInfo) -> ValueType {
+    type_id
+        .parse()
+        .unwrap_or(ValueType::Uint(8 * info.number_of_bytes.clamp(1, 32)))
+}
+
+/// Slot of `mapping[key]` for a mapping at `base` whose keys are of type `key_type`.
+fn mapping_slot_for(
+    key_info: &TypeInfo,
+    key_type: &str,
+    key: &str,
+    base: U256,
+) -> eyre::Result<U256, L1ScrollerError> {
+    let invalid = || L1ScrollerError::Layout(format!("invalid `{}` key `{key}`", key_info.label));
+    if key_info.encoding == "bytes" {
+        let mut preimage = if key_info.label == "string" {
+            key.as_bytes().to_vec()
+        } else {
+            hex::decode(key).map_err(|_| invalid())?
+        };
+        preimage.extend_from_slice(&base.to_be_bytes::<32>());
+        return Ok(keccak256(preimage).into());
+    }
+    let word = match value_type(key_type, key_info) {
+        ValueType::Address => Address::from_str(key).map_err(|_| invalid())?.into_word(),
+        ValueType::Uint(_) => U256::from_str(key).map_err(|_| invalid())?.into(),
+        ValueType::Int(_) => I256::from_str(key)
+            .map_err(|_| invalid())?
+            .into_raw()
+            .into(),
+        ValueType::Bool => match key {
+            "true" => B256::with_last_byte(1),
+            "false" => B256::ZERO,
+            _ => return Err(invalid()),
+        },
+        ValueType::FixedBytes(len) => {
+            let bytes = hex::decode(key).map_err(|_| invalid())?;
+            if bytes.len() > len {
+                return Err(invalid());
+            }
+            B256::right_padding_from(&bytes)
+        }
+        ValueType::String => return Err(invalid()),
+    };
+    Ok(mapping_slot(word, base))
+}
+
+impl LayoutVariable {
+    /// The value of type `ty` at `offset` in `slot`, named after the slot, to decode slots
+    /// without a storage layout. A long `string` also needs its data slots.
+    pub fn typed(slot: U256, offset: usize, ty: ValueType) -> Self {
+        Self {
+            name: format!("{slot:#x}"),
+            type_label: ty.to_string(),
+            slot,
+            offset,
+            kind: match ty {
+                ValueType::String => VariableKind::String,
+                ty => VariableKind::Value(ty),
+            },
+        }
+    }
+
+    /// Data slots of a long `string` or `bytes` value whose declaration slot holds `word`.
+    pub fn data_slots(&self, word: B256) -> Result<Vec<U256>, StorageError> {
+        match self.kind {
+            VariableKind::String | VariableKind::Bytes => {
+                StringSlot::decode(word)?.data_slots(self.slot)
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Decodes the value from the `words` read, `None` if a slot is missing.
+    pub fn decode(
+        &self,
+        words: &BTreeMap<U256, B256>,
+    ) -> Result<Option<DecodedValue>, StorageError> {
+        let Some(&word) = words.get(&self.slot) else {
+            return Ok(None);
+        };
+        let bytes = match self.kind {
+            VariableKind::Value(ty) => return decode_word(word, self.offset, ty).map(Some),
+            VariableKind::Length => return Ok(Some(DecodedValue::Uint(word.into()))),
+            VariableKind::String | VariableKind::Bytes => match StringSlot::decode(word)? {
+                StringSlot::Short(bytes) => bytes,
+                string @ StringSlot::Long { len } => {
+                    let slots = string.data_slots(self.slot)?;
+                    let mut data = Vec::with_capacity(32 * slots.len());
+                    for slot in slots {
+                        let Some(word) = words.get(&slot) else {
+                            return Ok(None);
+                        };
+                        data.extend_from_slice(word.as_slice());
+                    }
+                    data.truncate(len);
+                    Bytes::from(data)
+                }
+            },
+        };
+        Ok(Some(match self.kind {
+            VariableKind::String => DecodedValue::String(String::from_utf8_lossy(&bytes).into()),
+            _ => DecodedValue::Bytes(bytes),
+        }))
+    }
+}
+
+/// Distinct slots holding `variables`, in slot order.
+pub fn slots(variables: &[LayoutVariable]) -> Vec<U256> {
+    let slots: BTreeSet<U256> = variables.iter().map(|variable| variable.slot).collect();
+    slots.into_iter().collect()
+}
+
+/// Slots still to read for the long strings and bytes among `variables`.
+pub fn data_slots(
+    variables: &[LayoutVariable],
+    words: &BTreeMap<U256, B256>,
+) -> Result<Vec<U256>, StorageError> {
+    let mut slots = BTreeSet::new();
+    for variable in variables {
+        if let Some(&word) = words.get(&variable.slot) {
+            slots.extend(variable.data_slots(word)?);
+        }
+    }
+    Ok(slots
+        .into_iter()
+        .filter(|slot| !words.contains_key(slot))
+        .collect())
+}
+
+/// Reads every slot `variables` need with `read`, first the declaration slots and then the
+/// data slots of long strings and bytes, at most [`MAX_DATA_SLOTS`] of them.
+pub async fn collect_words<E: From<L1ScrollerError>>(
+    variables: &[LayoutVariable],
+    mut read: impl AsyncFnMut(Vec<U256>) -> Result<Vec<B256>, E>,
+) -> Result<BTreeMap<U256, B256>, E> {
+    let mut words = BTreeMap::new();
+    let mut pending = slots(variables);
+    let mut data_read = 0;
+    while !pending.is_empty() {
+        let read = read(pending.clone()).await?;
+        words.extend(pending.into_iter().zip(read));
+        pending = data_slots(variables, &words).map_err(L1ScrollerError::from)?;
+        data_read += pending.len();
+        if data_read > MAX_DATA_SLOTS {
+            return Err(L1ScrollerError::Layout(format!(
+                "long strings and bytes need more than {MAX_DATA_SLOTS} data slots"
+            ))
+            .into());
+        }
+    }
+    Ok(words)
+}
+
+/// Decodes every variable whose slots are in `words`.
+pub fn decode(
+    variables: &[LayoutVariable],
+    words: &BTreeMap<U256, B256>,
+) -> Result<Vec<LayoutValue>, StorageError> {
+    let mut values = Vec::new();
+    for variable in variables {
+        if let Some(value) = variable.decode(words)? {
+            values.push(LayoutValue {
+                name: variable.name.clone(),
+                type_label: variable.type_label.clone(),
+                slot: variable.slot,
+                offset: variable.offset,
+                value,
+            });
+        }
+    }
+    Ok(values)
+}
+
+#[cfg(feature = "transport")]
+impl Scroller<L1ScrollerContracttype> {
+    /// Reads and decodes every variable of `layout` stored by `l1_contract_addr`, at the
+    /// L2 `block`. See [`StorageLayout::variables`].
+    pub async fn read_layout(
+        &self,
+        l1_contract_addr: Address,
+        layout: &StorageLayout,
+        keys: &[MappingKey],
+        block: BlockId,
+    ) -> eyre::Result<Vec<LayoutValue>, L1ScrollerError> {
+        let variables = layout.variables(keys)?;
+        let words = collect_words(&variables, async |slots: Vec<U256>| {
+            self.read_words_at(l1_contract_addr, &slots, block).await
+        })
+        .await?;
+        Ok(decode(&variables, &words)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+    use crate::storage::address_mapping_slot;
+
+    fn layout() -> StorageLayout {
+        StorageLayout::from_json(include_str!("../tests/fixtures/storage_layout.json")).unwrap()
+    }
+
+    #[test]
+    fn test_variables() {
+        let owner = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+        let spender = address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+        let keys: Vec<MappingKey> = [
+            format!("balances={owner}"),
+            format!("allowances={owner},{spender}"),
+            "history=1".to_string(),
+        ]
+        .iter()
+        .map(|key| key.parse().unwrap())
+        .collect();
+        let variables = layout().variables(&keys).unwrap();
+        let names: Vec<&str> = variables.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(
+            names[..8],
+            [
+                "owner",
+                "fee",
+                "paused",
+                "name",
+                &format!("balances[{owner}]"),
+                &format!("allowances[{owner}][{spender}]"),
+                "config.cap",
+                "config.start",
+            ]
+        );
+        let by_name = |name: &str| variables.iter().find(|v| v.name == name).unwrap();
+
+        assert_eq!(by_name("fee").offset, 20);
+        assert_eq!(
+            by_name(&format!("balances[{owner}]")).slot,
+            address_mapping_slot(owner, U256::from(3))
+        );
+        assert_eq!(
+            by_name(&format!("allowances[{owner}][{spender}]")).slot,
+            mapping_slot(
+                spender.into_word(),
+                address_mapping_slot(owner, U256::from(4))
+            )
+        );
+        assert_eq!(by_name("config.admin").slot, U256::from(6));
+        assert_eq!(
+            by_name("history[1]").slot,
+            data_slot(U256::from(7)) + U256::from(1)
+        );
+        assert_eq!(
+            (by_name("flags[3]").slot, by_name("flags[3]").offset),
+            (U256::from(8), 3)
+        );
+    }
+
+    #[test]
+    fn test_decode() {
+        let variables = layout().variables(&[]).unwrap();
+        let mut slot0 = [0u8; 32];
+        slot0[12..]
+            .copy_from_slice(address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC").as_slice());
+        slot0[11] = 7;
+        let long_name = "a storage layout string longer than 31 bytes";
+        let mut words = BTreeMap::from([
+            (U256::from(0), B256::from(slot0)),
+            (
+                U256::from(2),
+                B256::from(U256::from(2 * long_name.len() + 1)),
+            ),
+        ]);
+        assert_eq!(data_slots(&variables, &words).unwrap().len(), 2);
+        for (i, chunk) in long_name.as_bytes().chunks(32).enumerate() {
+            words.insert(
+                data_slot(U256::from(2)) + U256::from(i),
+                B256::right_padding_from(chunk),
+            );
+        }
+
+        let values = decode(&variables, &words).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_eq!(
+            values[0].value,
+            DecodedValue::Address(address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC"))
+        );
+        assert_eq!(values[1].value, DecodedValue::Uint(U256::from(7)));
+        assert_eq!(values[2].value, DecodedValue::String(long_name.into()));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_string_slot() {
+        let variables = layout().variables(&[]).unwrap();
+        // Odd, so a long string, but far past `MAX_STRING_LEN`.
+        let words = BTreeMap::from([(U256::from(2), B256::from(U256::MAX))]);
+        assert!(data_slots(&variables, &words).is_err());
+        assert!(decode(&variables, &words).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_collect_words_is_bounded() {
+        let variables: Vec<LayoutVariable> = (0..3)
+            .map(|slot| LayoutVariable {
+                name: format!("s{slot}"),
+                type_label: "string".into(),
+                slot: U256::from(slot),
+                offset: 0,
+                kind: VariableKind::String,
+            })
+            .collect();
+        let longest = B256::from(U256::from(2 * crate::storage::MAX_STRING_LEN + 1));
+        let result = collect_words(&variables, async |slots: Vec<U256>| {
+            Ok::<_, L1ScrollerError>(vec![longest; slots.len()])
+        })
+        .await;
+        assert!(matches!(result, Err(L1ScrollerError::Layout(_))));
+    }
+}
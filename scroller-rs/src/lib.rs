@@ -1,4 +1,40 @@
+#[cfg(any(feature = "transport", feature = "erc20", feature = "erc721"))]
 pub mod bindings;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
+pub mod cache;
+#[cfg(feature = "transport")]
+pub mod consistency;
+#[cfg(all(feature = "http", feature = "erc20", feature = "erc721"))]
+pub mod deploy;
+#[cfg(feature = "transport")]
+pub mod deployments;
+#[cfg(all(feature = "cache", not(target_arch = "wasm32")))]
+pub mod disk_cache;
+#[cfg(feature = "transport")]
+pub mod gas;
+#[cfg(feature = "transport")]
+pub mod gas_plan;
+#[cfg(feature = "transport")]
 pub mod l1scroller;
+#[cfg(feature = "layout")]
+pub mod layout;
+#[cfg(all(test, feature = "http", not(target_arch = "wasm32")))]
+mod mock;
+pub mod precompile;
+#[cfg(all(feature = "pubsub", not(target_arch = "wasm32")))]
+pub mod pubsub;
+#[cfg(all(feature = "server", not(target_arch = "wasm32")))]
+pub mod rpc;
+#[cfg(all(feature = "server", not(target_arch = "wasm32")))]
+pub mod server;
+#[cfg(all(feature = "layout", feature = "transport"))]
+pub mod snapshot;
+#[cfg(feature = "transport")]
+pub mod telemetry;
+#[cfg(all(feature = "transport", not(target_arch = "wasm32")))]
+pub mod watch;
 
 pub mod error;
+
+pub use scroller_core::{erc20, erc721, storage};
@@ -0,0 +1,105 @@
+//! In-memory JSON-RPC transport for tests.
+
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use alloy::{
+    providers::ProviderBuilder,
+    rpc::{
+        client::RpcClient,
+        json_rpc::{ErrorPayload, RequestPacket, Response, ResponsePacket, ResponsePayload},
+    },
+    transports::{BoxTransport, TransportError, TransportFut},
+};
+use serde_json::{value::to_raw_value, Value};
+
+use crate::l1scroller::FilledProvider;
+
+type Handler = dyn Fn(&str, &Value) -> Result<Value, ErrorPayload> + Send + Sync;
+
+/// Transport answering every request with `handler(method, params)` and recording the
+/// requests it saw.
+#[derive(Clone)]
+pub(crate) struct MockTransport {
+    handler: Arc<Handler>,
+    requests: Arc<Mutex<Vec<(String, Value)>>>,
+}
+
+impl MockTransport {
+    pub(crate) fn new(
+        handler: impl Fn(&str, &Value) -> Result<Value, ErrorPayload> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            handler: Arc::new(handler),
+            requests: Arc::default(),
+        }
+    }
+
+    pub(crate) fn client(&self) -> RpcClient<BoxTransport> {
+        RpcClient::new(self.clone(), true).boxed()
+    }
+
+    pub(crate) fn provider(&self) -> FilledProvider {
+        ProviderBuilder::new()
+            .with_recommended_fillers()
+            .on_client(self.client())
+    }
+
+    /// Method and params of every request so far, in order.
+    pub(crate) fn requests(&self) -> Vec<(String, Value)> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Params of the requests to `method` so far.
+    pub(crate) fn params(&self, method: &str) -> Vec<Value> {
+        self.requests()
+            .into_iter()
+            .filter(|(m, _)| m == method)
+            .map(|(_, params)| params)
+            .collect()
+    }
+}
+
+impl tower::Service<RequestPacket> for MockTransport {
+    type Response = ResponsePacket;
+    type Error = TransportError;
+    type Future = TransportFut<'static>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, packet: RequestPacket) -> Self::Future {
+        let (batch, requests) = match packet {
+            RequestPacket::Single(request) => (false, vec![request]),
+            RequestPacket::Batch(requests) => (true, requests),
+        };
+        let mut responses: Vec<Response> = requests
+            .iter()
+            .map(|request| {
+                let params = request.params().map_or(Value::Null, |params| {
+                    serde_json::from_str(params.get()).unwrap()
+                });
+                self.requests
+                    .lock()
+                    .unwrap()
+                    .push((request.method().to_owned(), params.clone()));
+                let payload = match (self.handler)(request.method(), &params) {
+                    Ok(result) => ResponsePayload::Success(to_raw_value(&result).unwrap()),
+                    Err(err) => ResponsePayload::Failure(err),
+                };
+                Response {
+                    id: request.id().clone(),
+                    payload,
+                }
+            })
+            .collect();
+        let packet = match batch {
+            true => ResponsePacket::Batch(responses),
+            false => ResponsePacket::Single(responses.remove(0)),
+        };
+        Box::pin(async move { Ok(packet) })
+    }
+}
@@ -0,0 +1,57 @@
+//! Byte format of the L1SLOAD precompile.
+//!
+//! The input is the 20-byte L1 contract address followed by one 32-byte big-endian
+//! word per storage slot, i.e. `abi.encodePacked(l1Contract, slots)` as built by
+//! `L1Scroller.sol`. The output is one 32-byte word per slot, in the same order.
+//!
+//! The encoding lives in `scroller_core::precompile`, re-exported here next to the
+//! helpers that call the precompile over RPC.
+
+#[cfg(feature = "transport")]
+use alloy::{
+    network::TransactionBuilder,
+    primitives::{Address, U256},
+    providers::Provider,
+    rpc::types::{BlockId, TransactionRequest},
+    sol,
+    sol_types::SolCall,
+    transports::{Transport, TransportError, TransportResult},
+};
+
+pub use scroller_core::precompile::*;
+
+#[cfg(feature = "transport")]
+sol! {
+    interface IL1Blocks {
+        function latestBlockNumber() external view returns (uint256);
+    }
+}
+
+/// Builds a transaction calling the precompile directly, for `eth_call`, tracing or
+/// simulation.
+#[cfg(feature = "transport")]
+pub fn l1sload_call(
+    l1_contract: Address,
+    slots: &[U256],
+) -> Result<TransactionRequest, PrecompileError> {
+    Ok(TransactionRequest::default()
+        .with_to(L1SLOAD_ADDRESS)
+        .with_input(encode_l1sload_input(l1_contract, slots)?))
+}
+
+/// Latest L1 block number known to L2 at the L2 `block`, i.e. the block L1SLOAD reads
+/// from there.
+#[cfg(feature = "transport")]
+pub async fn latest_l1_block<T, P>(provider: &P, block: BlockId) -> TransportResult<u64>
+where
+    T: Transport + Clone,
+    P: Provider<T>,
+{
+    let tx = TransactionRequest::default()
+        .with_to(L1_BLOCKS_ADDRESS)
+        .with_input(IL1Blocks::latestBlockNumberCall {}.abi_encode());
+    let out = provider.call(&tx).block(block).await?;
+    let block = IL1Blocks::latestBlockNumberCall::abi_decode_returns(&out, true)
+        .map_err(TransportError::local_usage)?;
+    Ok(block._0.saturating_to())
+}
@@ -0,0 +1,441 @@
+//! WebSocket and IPC connections.
+//!
+//! A `Scroller` created over a pubsub transport subscribes to `newHeads` and, for every
+//! new L2 block, reads the L1 block visible through L1Blocks at it. That [`Head`] then
+//! replaces polling: [`Scroller::read_words`] reads at it without looking the L1 block up,
+//! an attached cache drops entries once the L1 block moves past them, and
+//! [`Scroller::watch`] re-reads on new heads instead of on a timer.
+//!
+//! Dropped connections are retried with exponential backoff (see [`ReconnectConfig`]);
+//! once reconnected, alloy re-sends in-flight requests and re-subscribes `newHeads`. While
+//! no head arrives for [`MAX_HEAD_AGE`], e.g. during reconnection, the last one is treated
+//! as stale: reads look the latest block up again and `watch` polls on its timer.
+
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+#[cfg(feature = "ipc")]
+use std::path::PathBuf;
+
+#[cfg(any(feature = "ws", feature = "ipc"))]
+use alloy::rpc::client::BuiltInConnectionString;
+#[cfg(feature = "ipc")]
+use alloy::transports::ipc::IpcConnect;
+#[cfg(feature = "ws")]
+use alloy::transports::ws::WsConnect;
+use alloy::{
+    primitives::Address,
+    providers::{Provider, ProviderBuilder},
+    pubsub::{ConnectionHandle, PubSubConnect},
+    rpc::{client::ClientBuilder, types::BlockId},
+    transports::TransportResult,
+};
+use futures::StreamExt;
+use tokio::{runtime::Handle, sync::watch, task::JoinHandle};
+
+use crate::{
+    cache::SlotCache,
+    error::L1ScrollerError,
+    l1scroller::{FilledProvider, L1ScrollerContracttype, Scroller},
+    precompile::latest_l1_block,
+    telemetry::{self, observe},
+};
+
+/// Age past which the latest head is stale and no longer used.
+pub const MAX_HEAD_AGE: Duration = Duration::from_secs(30);
+
+/// Backoff between attempts to re-establish a dropped pubsub connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectConfig {
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Give up after this many failed attempts in a row, `None` to retry forever. Once
+    /// given up, requests fail and `Scroller` falls back to polling.
+    pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_attempts: None,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+/// [`PubSubConnect`] that keeps retrying `inner` when the connection drops, instead of
+/// shutting the transport down after one failed attempt.
+#[derive(Debug, Clone)]
+pub struct Reconnecting<C> {
+    inner: C,
+    config: ReconnectConfig,
+}
+
+impl<C> Reconnecting<C> {
+    pub fn new(inner: C, config: ReconnectConfig) -> Self {
+        Self { inner, config }
+    }
+}
+
+impl<C: PubSubConnect> PubSubConnect for Reconnecting<C> {
+    fn is_local(&self) -> bool {
+        self.inner.is_local()
+    }
+
+    fn connect(&self) -> impl Future<Output = TransportResult<ConnectionHandle>> + Send {
+        self.inner.connect()
+    }
+
+    async fn try_reconnect(&self) -> TransportResult<ConnectionHandle> {
+        let mut attempt = 1;
+        loop {
+            match self.inner.connect().await {
+                Ok(handle) => {
+                    telemetry::reconnect_attempt(true);
+                    tracing::info!(attempt, "reconnected");
+                    return Ok(handle);
+                }
+                Err(err) if self.config.max_attempts.is_some_and(|max| attempt >= max) => {
+                    telemetry::reconnect_attempt(false);
+                    tracing::error!(attempt, error = %err, "giving up reconnecting");
+                    return Err(err);
+                }
+                Err(err) => {
+                    telemetry::reconnect_attempt(false);
+                    let backoff = self.config.backoff(attempt);
+                    tracing::warn!(attempt, ?backoff, error = %err, "reconnect failed");
+                    tokio::time::sleep(backoff).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+/// WebSocket or IPC endpoint parsed from a connection string.
+#[cfg(any(feature = "ws", feature = "ipc"))]
+#[derive(Debug, Clone)]
+pub enum Endpoint {
+    #[cfg(feature = "ws")]
+    Ws(WsConnect),
+    #[cfg(feature = "ipc")]
+    Ipc(IpcConnect<PathBuf>),
+}
+
+#[cfg(any(feature = "ws", feature = "ipc"))]
+impl Endpoint {
+    /// Parses `rpc_url` like alloy's builtin connection strings, returning `None` for
+    /// HTTP URLs.
+    pub fn parse(rpc_url: &str) -> TransportResult<Option<Self>> {
+        Ok(match rpc_url.parse::<BuiltInConnectionString>()? {
+            #[cfg(feature = "ws")]
+            BuiltInConnectionString::Ws(url, auth) => Some(Self::Ws(match auth {
+                Some(auth) => WsConnect::new(url).with_auth(auth),
+                None => WsConnect::new(url),
+            })),
+            #[cfg(feature = "ipc")]
+            BuiltInConnectionString::Ipc(path) => Some(Self::Ipc(IpcConnect::new(path))),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(any(feature = "ws", feature = "ipc"))]
+impl PubSubConnect for Endpoint {
+    fn is_local(&self) -> bool {
+        match self {
+            #[cfg(feature = "ws")]
+            Self::Ws(connect) => connect.is_local(),
+            #[cfg(feature = "ipc")]
+            Self::Ipc(connect) => connect.is_local(),
+        }
+    }
+
+    async fn connect(&self) -> TransportResult<ConnectionHandle> {
+        match self {
+            #[cfg(feature = "ws")]
+            Self::Ws(connect) => connect.connect().await,
+            #[cfg(feature = "ipc")]
+            Self::Ipc(connect) => connect.connect().await,
+        }
+    }
+}
+
+/// Latest L2 block seen through `newHeads`, with the L1 block visible at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Head {
+    pub l2_block: u64,
+    pub l1_block: u64,
+}
+
+/// Latest head and when it was received.
+pub(crate) type Heads = watch::Receiver<Option<(Head, Instant)>>;
+
+/// Background task following `newHeads`, stopped when dropped.
+#[derive(Debug)]
+pub(crate) struct HeadFollower {
+    heads: Heads,
+    task: JoinHandle<()>,
+    // Task started by the last `expire` call.
+    expiry: Mutex<Option<JoinHandle<()>>>,
+    // Runtime the transport runs on, so tasks can be spawned from outside of it.
+    runtime: Handle,
+}
+
+impl Drop for HeadFollower {
+    fn drop(&mut self) {
+        self.task.abort();
+        if let Some(expiry) = self.expiry.get_mut().unwrap().take() {
+            expiry.abort();
+        }
+    }
+}
+
+impl HeadFollower {
+    async fn spawn(provider: FilledProvider) -> TransportResult<Self> {
+        let subscription = provider.subscribe_blocks().await?;
+        let (tx, heads) = watch::channel(None);
+        let runtime = Handle::current();
+        let task = runtime.spawn(async move {
+            let mut headers = subscription.into_stream();
+            while let Some(header) = headers.next().await {
+                let l2_block = header.number;
+                let l1_block = observe("latestBlockNumber", 0, async {
+                    latest_l1_block(&provider, BlockId::number(l2_block))
+                        .await
+                        .map_err(|_| L1ScrollerError::L1BlockError)
+                })
+                .await;
+                if let Ok(l1_block) = l1_block {
+                    tracing::debug!(l2_block, l1_block, "new head");
+                    tx.send_replace(Some((Head { l2_block, l1_block }, Instant::now())));
+                }
+            }
+            tracing::warn!("newHeads subscription ended, falling back to polling");
+        });
+        Ok(Self::new(heads, task, runtime))
+    }
+
+    fn new(heads: Heads, task: JoinHandle<()>, runtime: Handle) -> Self {
+        Self {
+            heads,
+            task,
+            expiry: Mutex::new(None),
+            runtime,
+        }
+    }
+
+    /// Latest head, `None` before the first one, once it is older than [`MAX_HEAD_AGE`]
+    /// and once the subscription ended.
+    pub(crate) fn latest(&self) -> Option<Head> {
+        if self.heads.has_changed().is_err() {
+            return None;
+        }
+        fresh(*self.heads.borrow())
+    }
+
+    pub(crate) fn subscribe(&self) -> Heads {
+        self.heads.clone()
+    }
+
+    /// Calls [`SlotCache::expire_before`] whenever the L1 block of the head advances,
+    /// replacing the task of any previous call.
+    pub(crate) fn expire(&self, cache: Arc<dyn SlotCache>) {
+        let mut heads = self.subscribe();
+        let task = self.runtime.spawn(async move {
+            let mut l1_block = 0;
+            while heads.changed().await.is_ok() {
+                let Some((head, _)) = *heads.borrow_and_update() else {
+                    continue;
+                };
+                if head.l1_block > l1_block {
+                    l1_block = head.l1_block;
+                    cache.expire_before(l1_block);
+                }
+            }
+        });
+        if let Some(previous) = self.expiry.lock().unwrap().replace(task) {
+            previous.abort();
+        }
+    }
+}
+
+/// The head of `latest` unless it is older than [`MAX_HEAD_AGE`].
+pub(crate) fn fresh(latest: Option<(Head, Instant)>) -> Option<Head> {
+    latest
+        .filter(|(_, received)| received.elapsed() <= MAX_HEAD_AGE)
+        .map(|(head, _)| head)
+}
+
+impl Scroller<L1ScrollerContracttype> {
+    /// Connects through `connect`, reconnecting as configured by `reconnect`, and follows
+    /// `newHeads`. Reads go through the L1Scroller at `scroller_addr`, or only through the
+    /// L1SLOAD precompile when it is `None`. See the [module docs](self).
+    pub async fn new_pubsub<C: PubSubConnect>(
+        connect: C,
+        scroller_addr: Option<Address>,
+        reconnect: ReconnectConfig,
+    ) -> eyre::Result<Self> {
+        let client = ClientBuilder::default()
+            .pubsub(Reconnecting::new(connect, reconnect))
+            .await?
+            .boxed();
+        let pr = ProviderBuilder::new()
+            .with_recommended_fillers()
+            .on_client(client);
+        let heads = HeadFollower::spawn(pr.clone()).await?;
+        Ok(Self::with_provider(pr, scroller_addr).with_heads(heads))
+    }
+
+    /// Connects to a `ws://` or `wss://` URL. See [`Scroller::new_pubsub`].
+    #[cfg(feature = "ws")]
+    pub async fn new_ws(url: &str, scroller_addr: Address) -> eyre::Result<Self> {
+        Self::new_pubsub(
+            WsConnect::new(url),
+            Some(scroller_addr),
+            ReconnectConfig::default(),
+        )
+        .await
+    }
+
+    /// Connects to the IPC socket at `path`. See [`Scroller::new_pubsub`].
+    #[cfg(feature = "ipc")]
+    pub async fn new_ipc(path: impl Into<PathBuf>, scroller_addr: Address) -> eyre::Result<Self> {
+        Self::new_pubsub(
+            IpcConnect::new(path.into()),
+            Some(scroller_addr),
+            ReconnectConfig::default(),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let config = ReconnectConfig {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+            max_attempts: None,
+        };
+        let backoffs: Vec<u128> = (1..=6)
+            .map(|attempt| config.backoff(attempt).as_millis())
+            .collect();
+        assert_eq!(backoffs, [100, 200, 400, 800, 1000, 1000]);
+        assert_eq!(config.backoff(u32::MAX), config.max_backoff);
+    }
+
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_reconnect_attempts_are_counted() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        use alloy::transports::TransportErrorKind;
+        use metrics_exporter_prometheus::PrometheusBuilder;
+
+        /// Refuses as many connections as its count, then connects.
+        #[derive(Clone)]
+        struct Flaky(Arc<AtomicU32>);
+
+        impl PubSubConnect for Flaky {
+            fn is_local(&self) -> bool {
+                true
+            }
+
+            async fn connect(&self) -> TransportResult<ConnectionHandle> {
+                match self.0.fetch_sub(1, Ordering::SeqCst) {
+                    0 => Ok(ConnectionHandle::new().0),
+                    _ => Err(TransportErrorKind::custom_str("connection refused")),
+                }
+            }
+        }
+
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let config = ReconnectConfig {
+            initial_backoff: Duration::from_millis(1),
+            ..Default::default()
+        };
+        Reconnecting::new(Flaky(Arc::new(AtomicU32::new(2))), config)
+            .try_reconnect()
+            .await
+            .unwrap();
+
+        let output = handle.render();
+        assert!(output.contains(r#"scroller_reconnect_attempts_total{outcome="failed"} 2"#));
+        assert!(output.contains(r#"scroller_reconnect_attempts_total{outcome="connected"} 1"#));
+    }
+
+    #[tokio::test]
+    async fn test_stale_head_is_ignored() {
+        let (tx, heads) = watch::channel(None);
+        let follower = HeadFollower::new(heads, tokio::spawn(async {}), Handle::current());
+        let head = Head {
+            l2_block: 100,
+            l1_block: 25,
+        };
+        tx.send_replace(Some((head, Instant::now())));
+        assert_eq!(follower.latest(), Some(head));
+        let stale = Instant::now() - MAX_HEAD_AGE - Duration::from_secs(1);
+        tx.send_replace(Some((head, stale)));
+        assert_eq!(follower.latest(), None);
+    }
+
+    #[cfg(feature = "cache")]
+    #[tokio::test]
+    async fn test_expire_replaces_previous_task() {
+        let (_tx, heads) = watch::channel(None);
+        let follower = HeadFollower::new(heads, tokio::spawn(async {}), Handle::current());
+        let cache: Arc<dyn SlotCache> = Arc::new(crate::cache::LruSlotCache::new(16));
+        follower.expire(cache.clone());
+        follower.expire(cache.clone());
+        tokio::task::yield_now().await;
+        // Held by `cache` and the second task only.
+        assert_eq!(Arc::strong_count(&cache), 2);
+        drop(follower);
+        tokio::task::yield_now().await;
+        assert_eq!(Arc::strong_count(&cache), 1);
+    }
+
+    #[cfg(any(feature = "ws", feature = "ipc"))]
+    #[test]
+    fn test_endpoint_parse() {
+        assert!(Endpoint::parse("http://127.0.0.1:8545").unwrap().is_none());
+        #[cfg(feature = "ws")]
+        assert!(matches!(
+            Endpoint::parse("ws://127.0.0.1:8546").unwrap(),
+            Some(Endpoint::Ws(_))
+        ));
+    }
+
+    #[cfg(feature = "ws")]
+    #[tokio::test]
+    async fn test_reconnect_gives_up() {
+        let connect = Reconnecting::new(
+            WsConnect::new("ws://127.0.0.1:1"),
+            ReconnectConfig {
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(1),
+                max_attempts: Some(3),
+            },
+        );
+        assert!(connect.try_reconnect().await.is_err());
+    }
+}
@@ -0,0 +1,618 @@
+//! JSON-RPC 2.0 methods served by `scroller-server` on `POST /rpc`, single or batched.
+//!
+//! | method                         | params                                  | result               |
+//! |--------------------------------|-----------------------------------------|----------------------|
+//! | `l1scroller_readSlot`          | `contract, slot, block?`                | 32-byte word         |
+//! | `l1scroller_readMultipleSlots` | `contract, [slot, ...], block?`         | 32-byte words        |
+//! | `l1scroller_readTyped`         | `contract, slot, type, offset?, block?` | decoded value        |
+//! | `l1scroller_erc20BalanceOf`    | `token, account, block?`                | quantity             |
+//! | `l1scroller_blockNumbers`      | `block?`                                | `{l2Block, l1Block}` |
+//!
+//! `block` is an L2 block number or `"latest"`, as in `eth_*` methods. Integers are
+//! returned as hex quantities, negative ones prefixed with `-`. A request, or a whole
+//! batch, reads at most [`MAX_BATCH_READS`] slots.
+//!
+//! The calls of a batch reading at the same `block` share one read, so they see the same
+//! L2 and L1 blocks. Other methods, such as `eth_chainId`, are forwarded to the node, see
+//! [`SlotSource::forward`]. Requests without an `id` are notifications and get no response.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use alloy::{
+    eips::BlockNumberOrTag,
+    primitives::{Address, U256},
+    transports::TransportError,
+};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    erc20,
+    server::{read_all, Read, ReadBudget, ServerError, SlotSource, SlotValue, MAX_BATCH_READS},
+    storage::{check_offset, DecodedValue, ValueType},
+};
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// Reading from the node failed.
+pub const SERVER_ERROR: i64 = -32000;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    pub(crate) fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+impl From<ServerError> for RpcError {
+    fn from(err: ServerError) -> Self {
+        match err {
+            ServerError::BadRequest(message) => RpcError::new(INVALID_PARAMS, message),
+            ServerError::Upstream(err) => RpcError::new(SERVER_ERROR, err.to_string()),
+        }
+    }
+}
+
+/// Errors returned by the node are passed on as they are, e.g. with their revert data.
+impl From<TransportError> for RpcError {
+    fn from(err: TransportError) -> Self {
+        match err.as_error_resp() {
+            Some(payload) => Self {
+                code: payload.code,
+                message: payload.message.to_string(),
+                data: payload
+                    .data
+                    .as_ref()
+                    .and_then(|data| serde_json::from_str(data.get()).ok()),
+            },
+            None => RpcError::new(SERVER_ERROR, err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({ "jsonrpc": "2.0", "id": id, "error": error }),
+    }
+}
+
+/// Axum handler for a JSON-RPC request or batch of requests.
+pub async fn handle<S: SlotSource>(State(source): State<Arc<S>>, body: String) -> Response {
+    let body: Value = match serde_json::from_str(&body) {
+        Ok(body) => body,
+        Err(err) => {
+            let error = RpcError::new(PARSE_ERROR, err.to_string());
+            return Json(response(Value::Null, Err(error))).into_response();
+        }
+    };
+    let (batch, requests) = match body {
+        Value::Array(requests) if requests.len() > MAX_BATCH_READS => {
+            let error = RpcError::new(
+                INVALID_REQUEST,
+                format!(
+                    "at most {MAX_BATCH_READS} calls per batch, got {}",
+                    requests.len()
+                ),
+            );
+            return Json(response(Value::Null, Err(error))).into_response();
+        }
+        Value::Array(requests) if !requests.is_empty() => (true, requests),
+        request => (false, vec![request]),
+    };
+    let mut responses = execute(&*source, requests).await;
+    match (batch, responses.is_empty()) {
+        (_, true) => StatusCode::NO_CONTENT.into_response(),
+        (true, false) => Json(Value::Array(responses)).into_response(),
+        (false, false) => Json(responses.remove(0)).into_response(),
+    }
+}
+
+/// Call to one of the gateway's own methods, reading `reads` at `block`.
+struct ReadCall {
+    block: Option<u64>,
+    reads: Vec<Read>,
+    output: Output,
+}
+
+/// How the values read for a [`ReadCall`] make up its result.
+enum Output {
+    Word,
+    Words,
+    Typed,
+    BlockNumbers,
+}
+
+impl Output {
+    fn result(&self, l2_block: u64, l1_block: u64, values: &[SlotValue]) -> Value {
+        match self {
+            Output::Word => json!(values[0].raw),
+            Output::Words => json!(values.iter().map(|value| value.raw).collect::<Vec<_>>()),
+            Output::Typed => typed(&values[0].value),
+            Output::BlockNumbers => json!({ "l2Block": l2_block, "l1Block": l1_block }),
+        }
+    }
+}
+
+enum Call {
+    Done(Result<Value, RpcError>),
+    Read(ReadCall),
+    Forward(String, Value),
+}
+
+/// Runs the calls of a request or batch and returns the responses to those with an id,
+/// in order. The calls reading at the same block are read together.
+async fn execute<S: SlotSource>(source: &S, requests: Vec<Value>) -> Vec<Value> {
+    let mut planned = ReadBudget::default();
+    let mut ids = Vec::with_capacity(requests.len());
+    let mut results = vec![None; requests.len()];
+    let mut reads: BTreeMap<Option<u64>, Vec<(usize, ReadCall)>> = BTreeMap::new();
+    let mut forwards = Vec::new();
+    for (i, request) in requests.into_iter().enumerate() {
+        let (id, call) = parse(request, &mut planned);
+        ids.push(id);
+        match call {
+            Call::Done(result) => results[i] = Some(result),
+            Call::Read(call) => reads.entry(call.block).or_default().push((i, call)),
+            Call::Forward(method, params) => forwards.push((i, method, params)),
+        }
+    }
+
+    let mut budget = ReadBudget::default();
+    for (block, calls) in reads {
+        let mut outputs = Vec::with_capacity(calls.len());
+        let mut call_reads = Vec::new();
+        for (i, call) in calls {
+            outputs.push((i, call.reads.len(), call.output));
+            call_reads.extend(call.reads);
+        }
+        match read_all(source, block, call_reads, &mut budget).await {
+            Ok((l2_block, l1_block, values)) => {
+                let mut values = values.into_iter();
+                for (i, len, output) in outputs {
+                    let values: Vec<SlotValue> = values.by_ref().take(len).collect();
+                    results[i] = Some(Ok(output.result(l2_block, l1_block, &values)));
+                }
+            }
+            Err(err) => {
+                let err = RpcError::from(err);
+                for (i, _, _) in outputs {
+                    results[i] = Some(Err(err.clone()));
+                }
+            }
+        }
+    }
+    for (i, method, params) in forwards {
+        results[i] = Some(source.forward(&method, params).await);
+    }
+
+    ids.into_iter()
+        .zip(results)
+        .filter_map(|(id, result)| Some(response(id?, result?)))
+        .collect()
+}
+
+/// Id and call of `request`. Notifications have no id, and those of the gateway's own
+/// methods are not run as they have no effect.
+fn parse(request: Value, budget: &mut ReadBudget) -> (Option<Value>, Call) {
+    let id = request.get("id").cloned();
+    let request: Request = match serde_json::from_value(request) {
+        Ok(request) => request,
+        Err(err) => {
+            let error = RpcError::new(INVALID_REQUEST, err.to_string());
+            return (Some(id.unwrap_or_default()), Call::Done(Err(error)));
+        }
+    };
+    let call = match plan(&request.method, &request.params) {
+        Ok(Some(_)) if id.is_none() => Call::Done(Ok(Value::Null)),
+        Ok(Some(call)) => match budget.spend(call.reads.len()) {
+            Ok(()) => Call::Read(call),
+            Err(err) => Call::Done(Err(err.into())),
+        },
+        Ok(None) => Call::Forward(request.method, request.params),
+        Err(err) => Call::Done(Err(err)),
+    };
+    (id, call)
+}
+
+/// Reads of a call to one of the gateway's own `l1scroller_*` methods, `None` for the
+/// methods to forward.
+fn plan(method: &str, params: &Value) -> Result<Option<ReadCall>, RpcError> {
+    if !method.starts_with("l1scroller_") {
+        return Ok(None);
+    }
+    let params = match params {
+        Value::Array(params) => params.as_slice(),
+        Value::Null => &[],
+        _ => return Err(RpcError::new(INVALID_PARAMS, "params must be an array")),
+    };
+    let call = |block, reads, output| {
+        Ok(Some(ReadCall {
+            block,
+            reads,
+            output,
+        }))
+    };
+    match method {
+        "l1scroller_readSlot" => {
+            let read = raw(param(params, 0)?, param(params, 1)?);
+            call(block(params, 2)?, vec![read], Output::Word)
+        }
+        "l1scroller_readMultipleSlots" => {
+            let contract: Address = param(params, 0)?;
+            let slots: Vec<U256> = param(params, 1)?;
+            let reads = slots.into_iter().map(|slot| raw(contract, slot)).collect();
+            call(block(params, 2)?, reads, Output::Words)
+        }
+        "l1scroller_readTyped" => {
+            let ty: String = param(params, 2)?;
+            let ty = ty
+                .parse::<ValueType>()
+                .map_err(|err| RpcError::new(INVALID_PARAMS, err))?;
+            let offset = optional(params, 3)?.unwrap_or_default();
+            check_offset(offset, ty.size()).map_err(ServerError::from)?;
+            let read = Read {
+                ty: Some(ty),
+                offset,
+                ..raw(param(params, 0)?, param(params, 1)?)
+            };
+            call(block(params, 4)?, vec![read], Output::Typed)
+        }
+        "l1scroller_erc20BalanceOf" => {
+            let account: Address = param(params, 1)?;
+            let read = Read {
+                ty: Some(ValueType::Uint(256)),
+                ..raw(param(params, 0)?, erc20::balance_slot(account))
+            };
+            call(block(params, 2)?, vec![read], Output::Typed)
+        }
+        "l1scroller_blockNumbers" => call(block(params, 0)?, Vec::new(), Output::BlockNumbers),
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("method `{method}` not found"),
+        )),
+    }
+}
+
+fn raw(l1_contract: Address, slot: U256) -> Read {
+    Read {
+        l1_contract,
+        slot,
+        ty: None,
+        offset: 0,
+    }
+}
+
+/// JSON of a decoded value, with integers as hex quantities like `eth_*` results.
+fn typed(value: &DecodedValue) -> Value {
+    match value {
+        DecodedValue::Uint(value) => json!(value),
+        DecodedValue::Int(value) => {
+            let (sign, abs) = value.into_sign_and_abs();
+            json!(format!(
+                "{}{abs:#x}",
+                if sign.is_negative() { "-" } else { "" }
+            ))
+        }
+        value => json!(value),
+    }
+}
+
+fn param<T: DeserializeOwned>(params: &[Value], index: usize) -> Result<T, RpcError> {
+    let value = params.get(index).cloned().unwrap_or(Value::Null);
+    serde_json::from_value(value)
+        .map_err(|err| RpcError::new(INVALID_PARAMS, format!("param {index}: {err}")))
+}
+
+fn optional<T: DeserializeOwned>(params: &[Value], index: usize) -> Result<Option<T>, RpcError> {
+    param(params, index)
+}
+
+fn block(params: &[Value], index: usize) -> Result<Option<u64>, RpcError> {
+    match optional(params, index)? {
+        None | Some(BlockNumberOrTag::Latest) => Ok(None),
+        Some(BlockNumberOrTag::Number(number)) => Ok(Some(number)),
+        Some(tag) => Err(RpcError::new(
+            INVALID_PARAMS,
+            format!("unsupported block `{tag}`"),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use alloy::primitives::{address, B256, I256};
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::{
+        error::L1ScrollerError,
+        server::{router, SnapshotSource},
+        snapshot::Snapshot,
+    };
+
+    const TOKEN: Address = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+    const ACCOUNT: Address = address!("fA75fa50f36bb87669d0D4B8382BeC1C1C9570eC");
+
+    fn snapshot() -> SnapshotSource {
+        SnapshotSource::new([Snapshot {
+            l1_contract: TOKEN,
+            l2_block: 1000,
+            l1_block: 250,
+            slots: [
+                (U256::from(2), B256::with_last_byte(0x64)),
+                (erc20::balance_slot(ACCOUNT), B256::with_last_byte(0x2a)),
+            ]
+            .into(),
+        }])
+    }
+
+    /// [`SnapshotSource`] recording the calls made to it, behind a node answering
+    /// `eth_chainId` and reverting everything else.
+    #[derive(Default)]
+    struct Node {
+        snapshot: SnapshotSource,
+        calls: Mutex<Vec<String>>,
+    }
+
+    impl Node {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    impl SlotSource for Node {
+        async fn l2_block(&self) -> Result<u64, L1ScrollerError> {
+            self.record("l2_block".into());
+            self.snapshot.l2_block().await
+        }
+
+        async fn l1_block(&self, l2_block: u64) -> Result<u64, L1ScrollerError> {
+            self.record(format!("l1_block {l2_block}"));
+            self.snapshot.l1_block(l2_block).await
+        }
+
+        async fn read_words(
+            &self,
+            l1_contract: Address,
+            slots: &[U256],
+            l2_block: u64,
+            l1_block: u64,
+        ) -> Result<Vec<B256>, L1ScrollerError> {
+            self.record(format!("read_words {} {l2_block}", slots.len()));
+            self.snapshot
+                .read_words(l1_contract, slots, l2_block, l1_block)
+                .await
+        }
+
+        async fn forward(&self, method: &str, _params: Value) -> Result<Value, RpcError> {
+            self.record(method.to_owned());
+            match method {
+                "eth_chainId" => Ok(json!("0x1")),
+                _ => Err(RpcError {
+                    data: Some(json!("0x08c379a0")),
+                    ..RpcError::new(3, "execution reverted")
+                }),
+            }
+        }
+    }
+
+    async fn post<S: SlotSource>(source: Arc<S>, body: Value) -> (StatusCode, Value) {
+        let request = Request::post("/rpc")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = router(source).oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        match body.is_empty() {
+            true => (status, Value::Null),
+            false => (status, serde_json::from_slice(&body).unwrap()),
+        }
+    }
+
+    async fn rpc(body: Value) -> Value {
+        post(Arc::new(snapshot()), body).await.1
+    }
+
+    #[tokio::test]
+    async fn test_methods() {
+        let response = rpc(json!([
+            { "jsonrpc": "2.0", "id": 1, "method": "l1scroller_readSlot", "params": [TOKEN, "0x2"] },
+            { "jsonrpc": "2.0", "id": 2, "method": "l1scroller_readMultipleSlots", "params": [TOKEN, ["0x2", "0x3"], "latest"] },
+            { "jsonrpc": "2.0", "id": 3, "method": "l1scroller_readTyped", "params": [TOKEN, "0x2", "uint8"] },
+            { "jsonrpc": "2.0", "id": 4, "method": "l1scroller_erc20BalanceOf", "params": [TOKEN, ACCOUNT, "0x3e8"] },
+            { "jsonrpc": "2.0", "id": 5, "method": "l1scroller_blockNumbers", "params": [] },
+        ]))
+        .await;
+        assert_eq!(
+            response[0]["result"],
+            B256::with_last_byte(0x64).to_string()
+        );
+        assert_eq!(response[1]["result"][1], B256::ZERO.to_string());
+        assert_eq!(response[2]["result"], "0x64");
+        assert_eq!(response[3]["result"], "0x2a");
+        assert_eq!(response[4]["result"]["l1Block"], 250);
+        assert_eq!(response[4]["id"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_errors() {
+        let response = rpc(json!({ "jsonrpc": "2.0", "id": 1, "method": "eth_call" })).await;
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let response = rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "l1scroller_readTyped",
+            "params": [TOKEN, "0x2", "uint7"],
+        }))
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        let response = rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "l1scroller_readTyped",
+            "params": [TOKEN, "0x2", "uint256", usize::MAX],
+        }))
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        let response = rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "l1scroller_readSlot",
+            "params": [TOKEN],
+        }))
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_bounded() {
+        let slots: Vec<String> = (0..=MAX_BATCH_READS).map(|i| format!("{i:#x}")).collect();
+        let response = rpc(json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "l1scroller_readMultipleSlots",
+            "params": [TOKEN, slots],
+        }))
+        .await;
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+
+        // Each call is within the limit, the batch as a whole is not.
+        let call = json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "l1scroller_readMultipleSlots",
+            "params": [TOKEN, slots[..MAX_BATCH_READS / 2]],
+        });
+        let response = rpc(json!([call, call, call])).await;
+        assert!(response[1]["result"].is_array());
+        assert_eq!(response[2]["error"]["code"], INVALID_PARAMS);
+
+        let calls = vec![
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "l1scroller_blockNumbers" });
+            MAX_BATCH_READS + 1
+        ];
+        let response = rpc(Value::Array(calls)).await;
+        assert_eq!(response["error"]["code"], INVALID_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_batch_reads_once_per_block() {
+        let node = Arc::new(Node {
+            snapshot: snapshot(),
+            ..Default::default()
+        });
+        let (_, response) = post(
+            node.clone(),
+            json!([
+                { "jsonrpc": "2.0", "id": 1, "method": "l1scroller_readSlot", "params": [TOKEN, "0x2"] },
+                { "jsonrpc": "2.0", "id": 2, "method": "eth_chainId" },
+                { "jsonrpc": "2.0", "id": 3, "method": "l1scroller_readTyped", "params": [TOKEN, "0x2", "uint8", 0, "0x3e8"] },
+                { "jsonrpc": "2.0", "id": 4, "method": "l1scroller_readMultipleSlots", "params": [TOKEN, ["0x2", "0x3"], "latest"] },
+                { "jsonrpc": "2.0", "method": "l1scroller_readSlot", "params": [TOKEN, "0x4"] },
+                { "jsonrpc": "2.0", "id": 5, "method": "l1scroller_blockNumbers" },
+                { "jsonrpc": "2.0", "id": 6, "method": "eth_call", "params": [{}, "latest"] },
+            ]),
+        )
+        .await;
+
+        // The notification gets no response.
+        let ids: Vec<_> = response
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|response| response["id"].as_u64().unwrap())
+            .collect();
+        assert_eq!(ids, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(
+            response[0]["result"],
+            B256::with_last_byte(0x64).to_string()
+        );
+        assert_eq!(response[1]["result"], "0x1");
+        assert_eq!(response[2]["result"], "0x64");
+        assert_eq!(
+            response[3]["result"][0],
+            B256::with_last_byte(0x64).to_string()
+        );
+        assert_eq!(response[4]["result"]["l2Block"], 1000);
+        assert_eq!(response[5]["error"]["code"], 3);
+        assert_eq!(response[5]["error"]["data"], "0x08c379a0");
+
+        // One block lookup and one read for the latest block, one read at block 1000.
+        assert_eq!(
+            *node.calls.lock().unwrap(),
+            [
+                "l2_block",
+                "l1_block 1000",
+                "read_words 3 1000",
+                "l1_block 1000",
+                "read_words 1 1000",
+                "eth_chainId",
+                "eth_call",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_notifications_get_no_response() {
+        let notification = json!({ "jsonrpc": "2.0", "method": "eth_chainId" });
+        let (status, body) = post(Arc::<Node>::default(), notification.clone()).await;
+        assert_eq!((status, body), (StatusCode::NO_CONTENT, Value::Null));
+
+        let (status, body) =
+            post(Arc::<Node>::default(), json!([notification, notification])).await;
+        assert_eq!((status, body), (StatusCode::NO_CONTENT, Value::Null));
+
+        // `null` is an id.
+        let (status, body) = post(
+            Arc::<Node>::default(),
+            json!({ "jsonrpc": "2.0", "id": null, "method": "eth_chainId" }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["result"], "0x1");
+    }
+
+    #[test]
+    fn test_integers_are_quantities() {
+        assert_eq!(typed(&DecodedValue::Uint(U256::from(100))), "0x64");
+        assert_eq!(typed(&DecodedValue::Int(I256::MINUS_ONE)), "-0x1");
+        assert_eq!(typed(&DecodedValue::Bool(true)), true);
+    }
+}
@@ -0,0 +1,522 @@
+//! HTTP/JSON gateway serving L1SLOAD reads, used by the `scroller-server` binary.
+//!
+//! - `GET /v1/{l1_contract}/slot/{slot}?type=uint96&offset=0&block=123`
+//! - `POST /v1/batch` with `{"block": 123, "reads": [{"l1Contract": "0x..", "slot": "0x2",
+//!   "type": "uint96", "offset": 0}]}`
+//! - `POST /rpc` for the JSON-RPC `l1scroller_*` methods, see [`crate::rpc`]
+//!
+//! Every response carries the L2 block the values were read at and the L1 block visible
+//! through L1Blocks at that L2 block. Errors are returned as `{"error": "..."}`. A request
+//! reads at most [`MAX_BATCH_READS`] slots, including the data slots of long strings.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    sync::Arc,
+};
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    providers::Provider,
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    cache::ReadOptions,
+    error::L1ScrollerError,
+    l1scroller::{L1ScrollerContracttype, Scroller},
+    rpc::{self, RpcError, METHOD_NOT_FOUND},
+    snapshot::Snapshot,
+    storage::{check_offset, decode_word, DecodedValue, StorageError, StringSlot, ValueType},
+};
+
+/// Most slots read for one request, counting the data slots of long strings.
+pub const MAX_BATCH_READS: usize = 256;
+
+/// Where the gateway reads slots from.
+pub trait SlotSource: Send + Sync + 'static {
+    fn l2_block(&self) -> impl Future<Output = Result<u64, L1ScrollerError>> + Send;
+
+    /// L1 block visible through L1Blocks at `l2_block`.
+    fn l1_block(&self, l2_block: u64) -> impl Future<Output = Result<u64, L1ScrollerError>> + Send;
+
+    /// Raw words of `slots` at `l2_block`, which sees `l1_block`.
+    fn read_words(
+        &self,
+        l1_contract: Address,
+        slots: &[U256],
+        l2_block: u64,
+        l1_block: u64,
+    ) -> impl Future<Output = Result<Vec<B256>, L1ScrollerError>> + Send;
+
+    /// Result of a JSON-RPC call to `method`, which the gateway does not serve itself,
+    /// sent to the node. Sources without a node have no such methods.
+    fn forward(
+        &self,
+        method: &str,
+        _params: Value,
+    ) -> impl Future<Output = Result<Value, RpcError>> + Send {
+        let error = RpcError::new(METHOD_NOT_FOUND, format!("method `{method}` not found"));
+        std::future::ready(Err(error))
+    }
+}
+
+/// Reads through the L1SLOAD precompile, batched and served from the attached cache.
+impl SlotSource for Scroller<L1ScrollerContracttype> {
+    async fn l2_block(&self) -> Result<u64, L1ScrollerError> {
+        self.l2_block_number().await
+    }
+
+    async fn l1_block(&self, l2_block: u64) -> Result<u64, L1ScrollerError> {
+        self.l1_block_number_at(l2_block.into()).await
+    }
+
+    async fn read_words(
+        &self,
+        l1_contract: Address,
+        slots: &[U256],
+        l2_block: u64,
+        l1_block: u64,
+    ) -> Result<Vec<B256>, L1ScrollerError> {
+        let options = ReadOptions::default()
+            .at_l2_block(l2_block)
+            .at_l1_block(l1_block);
+        Scroller::read_words(self, l1_contract, slots, options).await
+    }
+
+    async fn forward(&self, method: &str, params: Value) -> Result<Value, RpcError> {
+        // Nodes expect an array even for methods without parameters.
+        let params = match params {
+            Value::Null => Value::Array(Vec::new()),
+            params => params,
+        };
+        Ok(self
+            .provider()
+            .raw_request(method.to_owned().into(), params)
+            .await?)
+    }
+}
+
+/// In-memory backend serving [`Snapshot`]s, for running the gateway without a Scroll
+/// node. Every block sees the same values, and slots missing from a snapshot read as zero.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotSource {
+    l2_block: u64,
+    l1_block: u64,
+    slots: HashMap<Address, BTreeMap<U256, B256>>,
+}
+
+impl SnapshotSource {
+    pub fn new(snapshots: impl IntoIterator<Item = Snapshot>) -> Self {
+        let mut source = Self::default();
+        for snapshot in snapshots {
+            source.l2_block = source.l2_block.max(snapshot.l2_block);
+            source.l1_block = source.l1_block.max(snapshot.l1_block);
+            source
+                .slots
+                .entry(snapshot.l1_contract)
+                .or_default()
+                .extend(snapshot.slots);
+        }
+        source
+    }
+}
+
+impl SlotSource for SnapshotSource {
+    async fn l2_block(&self) -> Result<u64, L1ScrollerError> {
+        Ok(self.l2_block)
+    }
+
+    async fn l1_block(&self, _l2_block: u64) -> Result<u64, L1ScrollerError> {
+        Ok(self.l1_block)
+    }
+
+    async fn read_words(
+        &self,
+        l1_contract: Address,
+        slots: &[U256],
+        _l2_block: u64,
+        _l1_block: u64,
+    ) -> Result<Vec<B256>, L1ScrollerError> {
+        let words = self.slots.get(&l1_contract);
+        Ok(slots
+            .iter()
+            .map(|slot| {
+                words
+                    .and_then(|words| words.get(slot))
+                    .copied()
+                    .unwrap_or_default()
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum ServerError {
+    BadRequest(String),
+    Upstream(L1ScrollerError),
+}
+
+impl From<L1ScrollerError> for ServerError {
+    fn from(err: L1ScrollerError) -> Self {
+        ServerError::Upstream(err)
+    }
+}
+
+impl From<StorageError> for ServerError {
+    fn from(err: StorageError) -> Self {
+        ServerError::BadRequest(err.to_string())
+    }
+}
+
+impl IntoResponse for ServerError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ServerError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
+            ServerError::Upstream(err) => (StatusCode::BAD_GATEWAY, err.to_string()),
+        };
+        (status, Json(json!({ "error": message }))).into_response()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SlotQuery {
+    #[serde(rename = "type")]
+    ty: Option<String>,
+    #[serde(default)]
+    offset: usize,
+    block: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchRead {
+    l1_contract: Address,
+    slot: U256,
+    #[serde(rename = "type")]
+    ty: Option<String>,
+    #[serde(default)]
+    offset: usize,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchRequest {
+    block: Option<u64>,
+    reads: Vec<BatchRead>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SlotValue {
+    pub(crate) l1_contract: Address,
+    pub(crate) slot: U256,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub(crate) ty: Option<String>,
+    pub(crate) raw: B256,
+    pub(crate) value: DecodedValue,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadResponse<T> {
+    l2_block: u64,
+    l1_block: u64,
+    #[serde(flatten)]
+    body: T,
+}
+
+/// One read of a request, with its type already parsed.
+pub(crate) struct Read {
+    pub(crate) l1_contract: Address,
+    pub(crate) slot: U256,
+    pub(crate) ty: Option<ValueType>,
+    pub(crate) offset: usize,
+}
+
+fn parse_type(ty: Option<&str>) -> Result<Option<ValueType>, ServerError> {
+    ty.map(|ty| ty.parse().map_err(ServerError::BadRequest))
+        .transpose()
+}
+
+/// Builds the gateway routes over `source`.
+pub fn router<S: SlotSource>(source: Arc<S>) -> Router {
+    Router::new()
+        .route("/v1/:l1_contract/slot/:slot", get(read_slot::<S>))
+        .route("/v1/batch", post(read_batch::<S>))
+        .route("/rpc", post(rpc::handle::<S>))
+        .with_state(source)
+}
+
+/// `GET /metrics` in the Prometheus text format, see [`crate::telemetry`].
+#[cfg(feature = "metrics")]
+pub fn metrics_router(handle: metrics_exporter_prometheus::PrometheusHandle) -> Router {
+    Router::new().route("/metrics", get(move || std::future::ready(handle.render())))
+}
+
+/// Serves `router` on `listener` until the process is stopped.
+pub async fn serve(listener: tokio::net::TcpListener, router: Router) -> std::io::Result<()> {
+    axum::serve(listener, router).await
+}
+
+async fn read_slot<S: SlotSource>(
+    State(source): State<Arc<S>>,
+    Path((l1_contract, slot)): Path<(String, String)>,
+    Query(query): Query<SlotQuery>,
+) -> Result<Json<ReadResponse<SlotValue>>, ServerError> {
+    let l1_contract = l1_contract
+        .parse()
+        .map_err(|_| ServerError::BadRequest(format!("invalid address `{l1_contract}`")))?;
+    let slot = slot
+        .parse()
+        .map_err(|_| ServerError::BadRequest(format!("invalid slot `{slot}`")))?;
+    let read = Read {
+        l1_contract,
+        slot,
+        ty: parse_type(query.ty.as_deref())?,
+        offset: query.offset,
+    };
+    let (l2_block, l1_block, mut values) = read_all(
+        &*source,
+        query.block,
+        vec![read],
+        &mut ReadBudget::default(),
+    )
+    .await?;
+    Ok(Json(ReadResponse {
+        l2_block,
+        l1_block,
+        body: values.remove(0),
+    }))
+}
+
+async fn read_batch<S: SlotSource>(
+    State(source): State<Arc<S>>,
+    Json(request): Json<BatchRequest>,
+) -> Result<Json<ReadResponse<serde_json::Value>>, ServerError> {
+    let reads = request
+        .reads
+        .into_iter()
+        .map(|read| {
+            Ok(Read {
+                l1_contract: read.l1_contract,
+                slot: read.slot,
+                ty: parse_type(read.ty.as_deref())?,
+                offset: read.offset,
+            })
+        })
+        .collect::<Result<Vec<_>, ServerError>>()?;
+    let (l2_block, l1_block, values) =
+        read_all(&*source, request.block, reads, &mut ReadBudget::default()).await?;
+    Ok(Json(ReadResponse {
+        l2_block,
+        l1_block,
+        body: json!({ "values": values }),
+    }))
+}
+
+/// Slots read so far on behalf of one request; the calls of a JSON-RPC batch share one.
+#[derive(Debug, Default)]
+pub(crate) struct ReadBudget {
+    used: usize,
+}
+
+impl ReadBudget {
+    /// Counts `slots` more reads, failing once more than [`MAX_BATCH_READS`] were made.
+    pub(crate) fn spend(&mut self, slots: usize) -> Result<(), ServerError> {
+        self.used = self.used.saturating_add(slots);
+        if self.used > MAX_BATCH_READS {
+            return Err(ServerError::BadRequest(format!(
+                "at most {MAX_BATCH_READS} slots per request, got {}",
+                self.used
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Reads and decodes `reads` at `block`, one batch per L1 contract, following long
+/// strings into their data slots.
+pub(crate) async fn read_all<S: SlotSource>(
+    source: &S,
+    block: Option<u64>,
+    reads: Vec<Read>,
+    budget: &mut ReadBudget,
+) -> Result<(u64, u64, Vec<SlotValue>), ServerError> {
+    budget.spend(reads.len())?;
+    for read in &reads {
+        if let Some(ty) = read.ty {
+            check_offset(read.offset, ty.size())?;
+        }
+    }
+    let l2_block = match block {
+        Some(block) => block,
+        None => source.l2_block().await?,
+    };
+    let l1_block = source.l1_block(l2_block).await?;
+
+    let mut slots: BTreeMap<Address, Vec<U256>> = BTreeMap::new();
+    for read in &reads {
+        slots.entry(read.l1_contract).or_default().push(read.slot);
+    }
+    let mut words: HashMap<(Address, U256), B256> = HashMap::new();
+    for (l1_contract, slots) in slots {
+        let read = source
+            .read_words(l1_contract, &slots, l2_block, l1_block)
+            .await?;
+        words.extend(slots.into_iter().map(|slot| (l1_contract, slot)).zip(read));
+    }
+
+    // Long strings are only followed once their data slots fit in the budget.
+    let mut strings = Vec::with_capacity(reads.len());
+    let mut total = 0;
+    for read in &reads {
+        let string = match read.ty {
+            Some(ValueType::String) => {
+                Some(StringSlot::decode(words[&(read.l1_contract, read.slot)])?)
+            }
+            _ => None,
+        };
+        if let Some(StringSlot::Long { len }) = string {
+            total += len.div_ceil(32);
+        }
+        strings.push(string);
+    }
+    budget.spend(total)?;
+
+    let mut values = Vec::with_capacity(reads.len());
+    for (read, string) in reads.into_iter().zip(strings) {
+        let raw = words[&(read.l1_contract, read.slot)];
+        let value = match (read.ty, string) {
+            (None, _) => DecodedValue::Bytes(raw.into()),
+            (Some(ValueType::String), Some(string @ StringSlot::Long { len })) => {
+                let data_slots = string.data_slots(read.slot)?;
+                let data = source
+                    .read_words(read.l1_contract, &data_slots, l2_block, l1_block)
+                    .await?
+                    .concat();
+                DecodedValue::String(
+                    String::from_utf8_lossy(&data[..len.min(data.len())]).into_owned(),
+                )
+            }
+            (Some(ty), _) => decode_word(raw, read.offset, ty)?,
+        };
+        values.push(SlotValue {
+            l1_contract: read.l1_contract,
+            slot: read.slot,
+            ty: read.ty.map(|ty| ty.to_string()),
+            raw,
+            value,
+        });
+    }
+    Ok((l2_block, l1_block, values))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+    };
+    use tower::ServiceExt;
+
+    use super::*;
+
+    const VAULT: Address = address!("A8E50c2607678747D9d8A24AC52234712bE41fD9");
+
+    fn source() -> Arc<SnapshotSource> {
+        let mut slot0 = [0u8; 32];
+        slot0[11] = 5;
+        slot0[31] = 0xff;
+        let mut name = [0u8; 32];
+        name[..5].copy_from_slice(b"Vault");
+        name[31] = 10;
+        // An odd uint, which reads as the length of a 16 KiB string.
+        let long = B256::from(U256::from(2 * 16384 + 1));
+        Arc::new(SnapshotSource::new([Snapshot {
+            l1_contract: VAULT,
+            l2_block: 1000,
+            l1_block: 250,
+            slots: [
+                (U256::from(0), slot0.into()),
+                (U256::from(2), name.into()),
+                (U256::from(3), long),
+            ]
+            .into(),
+        }]))
+    }
+
+    async fn send(request: Request<Body>) -> (StatusCode, serde_json::Value) {
+        let response = router(source()).oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_read_slot() {
+        let uri = format!("/v1/{VAULT}/slot/0?type=uint96&offset=20&block=1000");
+        let (status, body) = send(Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["l2Block"], 1000);
+        assert_eq!(body["l1Block"], 250);
+        assert_eq!(body["type"], "uint96");
+        assert_eq!(body["value"], "5");
+
+        let uri = format!("/v1/{VAULT}/slot/0?type=uint7");
+        let (status, body) = send(Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("uint7"));
+
+        let uri = format!("/v1/{VAULT}/slot/0?type=uint96&offset=21");
+        let (status, body) = send(Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("offset 21"));
+    }
+
+    #[tokio::test]
+    async fn test_string_reads_are_bounded() {
+        let uri = format!("/v1/{VAULT}/slot/3?type=string");
+        let (status, body) = send(Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("got 513"));
+
+        let uri = format!("/v1/{VAULT}/slot/0?type=string");
+        let (status, body) = send(Request::get(uri).body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap().contains("string length"));
+    }
+
+    #[tokio::test]
+    async fn test_batch() {
+        let request = json!({
+            "reads": [
+                { "l1Contract": VAULT, "slot": "0x2", "type": "string" },
+                { "l1Contract": VAULT, "slot": "0x0", "type": "address" },
+                { "l1Contract": VAULT, "slot": "0x9" },
+            ]
+        });
+        let request = Request::post("/v1/batch")
+            .header("content-type", "application/json")
+            .body(Body::from(request.to_string()))
+            .unwrap();
+        let (status, body) = send(request).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["l2Block"], 1000);
+        let values = body["values"].as_array().unwrap();
+        assert_eq!(values[0]["value"], "Vault");
+        assert_eq!(
+            values[1]["value"].as_str().unwrap().to_lowercase(),
+            "0x00000000000000000000000000000000000000ff"
+        );
+        assert_eq!(values[2]["raw"], B256::ZERO.to_string());
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    rpc::types::BlockId,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::L1ScrollerError,
+    l1scroller::{L1ScrollerContracttype, Scroller},
+    layout::{collect_words, LayoutVariable},
+    storage::{DecodedValue, StorageError},
+};
+
+/// Raw words of a set of slots of an L1 contract, as seen through L1SLOAD at one L2 block.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Snapshot {
+    pub l1_contract: Address,
+    pub l2_block: u64,
+    pub l1_block: u64,
+    pub slots: BTreeMap<U256, B256>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockRef {
+    pub l2_block: u64,
+    pub l1_block: u64,
+}
+
+/// A slot whose raw word differs between two snapshots; `None` when a snapshot does not
+/// contain the slot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SlotDiff {
+    pub slot: U256,
+    pub old: Option<B256>,
+    pub new: Option<B256>,
+}
+
+/// A storage layout variable whose decoded value differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariableDiff {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_label: String,
+    pub slot: U256,
+    pub offset: usize,
+    pub old: Option<DecodedValue>,
+    pub new: Option<DecodedValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+    pub l1_contract: Address,
+    pub from: BlockRef,
+    pub to: BlockRef,
+    pub slots: Vec<SlotDiff>,
+    /// Only filled by [`Snapshot::diff_with_layout`].
+    pub variables: Vec<VariableDiff>,
+}
+
+impl Snapshot {
+    pub fn block_ref(&self) -> BlockRef {
+        BlockRef {
+            l2_block: self.l2_block,
+            l1_block: self.l1_block,
+        }
+    }
+
+    /// Slots whose raw word changed from `self` to `to`. Use [`Snapshot::diff_with_layout`]
+    /// to also decode them, with the variables of a storage layout or
+    /// [`LayoutVariable::typed`] for single slots.
+    pub fn diff(&self, to: &Snapshot) -> SnapshotDiff {
+        let slots: BTreeSet<&U256> = self.slots.keys().chain(to.slots.keys()).collect();
+        SnapshotDiff {
+            l1_contract: self.l1_contract,
+            from: self.block_ref(),
+            to: to.block_ref(),
+            slots: slots
+                .into_iter()
+                .map(|slot| SlotDiff {
+                    slot: *slot,
+                    old: self.slots.get(slot).copied(),
+                    new: to.slots.get(slot).copied(),
+                })
+                .filter(|diff| diff.old != diff.new)
+                .collect(),
+            variables: Vec::new(),
+        }
+    }
+
+    /// Like [`Snapshot::diff`], also decoding `variables` in both snapshots and listing
+    /// those whose value changed.
+    pub fn diff_with_layout(
+        &self,
+        to: &Snapshot,
+        variables: &[LayoutVariable],
+    ) -> Result<SnapshotDiff, StorageError> {
+        let mut diff = self.diff(to);
+        for variable in variables {
+            let old = variable.decode(&self.slots)?;
+            let new = variable.decode(&to.slots)?;
+            if old != new {
+                diff.variables.push(VariableDiff {
+                    name: variable.name.clone(),
+                    type_label: variable.type_label.clone(),
+                    slot: variable.slot,
+                    offset: variable.offset,
+                    old,
+                    new,
+                });
+            }
+        }
+        Ok(diff)
+    }
+
+    pub fn from_json(json: &str) -> eyre::Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn to_json(&self) -> eyre::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn to_file(&self, path: impl AsRef<Path>) -> eyre::Result<()> {
+        Ok(std::fs::write(path, self.to_json()? + "\n")?)
+    }
+}
+
+impl SnapshotDiff {
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty() && self.variables.is_empty()
+    }
+
+    pub fn to_json(&self) -> eyre::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// One row per changed variable, then one per changed slot not covered by a variable,
+    /// with the columns `slot,offset,name,type,old_raw,new_raw,old_value,new_value`.
+    pub fn to_csv(&self) -> String {
+        let raw = |slot: &U256| {
+            let diff = self.slots.iter().find(|diff| diff.slot == *slot);
+            let word = |word: Option<B256>| word.map(|word| word.to_string()).unwrap_or_default();
+            match diff {
+                Some(diff) => (word(diff.old), word(diff.new)),
+                None => Default::default(),
+            }
+        };
+        let value = |value: &Option<DecodedValue>| {
+            value
+                .as_ref()
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        };
+
+        let mut csv = String::from("slot,offset,name,type,old_raw,new_raw,old_value,new_value\n");
+        let mut push = |row: [String; 8]| {
+            let row: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        };
+        for variable in &self.variables {
+            let (old_raw, new_raw) = raw(&variable.slot);
+            push([
+                format!("{:#x}", variable.slot),
+                variable.offset.to_string(),
+                variable.name.clone(),
+                variable.type_label.clone(),
+                old_raw,
+                new_raw,
+                value(&variable.old),
+                value(&variable.new),
+            ]);
+        }
+        for diff in &self.slots {
+            if self
+                .variables
+                .iter()
+                .any(|variable| variable.slot == diff.slot)
+            {
+                continue;
+            }
+            let (old_raw, new_raw) = raw(&diff.slot);
+            push([
+                format!("{:#x}", diff.slot),
+                String::new(),
+                String::new(),
+                String::new(),
+                old_raw,
+                new_raw,
+                String::new(),
+                String::new(),
+            ]);
+        }
+        csv
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Scroller<L1ScrollerContracttype> {
+    /// Reads `slots` and the slots holding `variables` (including the data of long strings
+    /// and bytes) at the L2 `block`.
+    pub async fn snapshot(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        variables: &[LayoutVariable],
+        block: BlockId,
+    ) -> eyre::Result<Snapshot, L1ScrollerError> {
+        let l2_block = match block.as_u64() {
+            Some(number) => number,
+            None => self.l2_block_number().await?,
+        };
+        let block = BlockId::number(l2_block);
+        let l1_block = self.l1_block_number_at(block).await?;
+
+        let mut words = collect_words(variables, async |slots: Vec<U256>| {
+            self.read_words_at(l1_contract_addr, &slots, block).await
+        })
+        .await?;
+        let missing: Vec<U256> = slots
+            .iter()
+            .filter(|slot| !words.contains_key(slot))
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        let read = self
+            .read_words_at(l1_contract_addr, &missing, block)
+            .await?;
+        words.extend(missing.into_iter().zip(read));
+
+        Ok(Snapshot {
+            l1_contract: l1_contract_addr,
+            l2_block,
+            l1_block,
+            slots: words,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy::primitives::address;
+
+    use super::*;
+    use crate::{layout::StorageLayout, storage::ValueType};
+
+    fn snapshot(l2_block: u64, slots: &[(u64, B256)]) -> Snapshot {
+        Snapshot {
+            l1_contract: address!("A8E50c2607678747D9d8A24AC52234712bE41fD9"),
+            l2_block,
+            l1_block: l2_block / 4,
+            slots: slots
+                .iter()
+                .map(|(slot, word)| (U256::from(*slot), *word))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_diff() {
+        let mut slot0 = [0u8; 32];
+        slot0[11] = 1;
+        let a = snapshot(100, &[(0, B256::from(slot0)), (1, B256::ZERO)]);
+        slot0[11] = 2;
+        let b = snapshot(
+            200,
+            &[
+                (0, B256::from(slot0)),
+                (1, B256::ZERO),
+                (9, B256::with_last_byte(5)),
+            ],
+        );
+
+        let layout =
+            StorageLayout::from_json(include_str!("../tests/fixtures/storage_layout.json"))
+                .unwrap();
+        let diff = a
+            .diff_with_layout(&b, &layout.variables(&[]).unwrap())
+            .unwrap();
+        assert_eq!(diff.slots.len(), 2);
+        assert_eq!(diff.slots[1].old, None);
+        assert_eq!(diff.variables.len(), 1);
+        assert_eq!(diff.variables[0].name, "fee");
+        assert_eq!(
+            diff.variables[0].new,
+            Some(DecodedValue::Uint(U256::from(2)))
+        );
+        assert_eq!((diff.from.l1_block, diff.to.l2_block), (25, 200));
+
+        let csv = diff.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("0x0,20,fee,uint96,"));
+        assert!(lines[1].ends_with(",1,2"));
+        assert!(lines[2].starts_with("0x9,,,,,"));
+
+        let typed = [LayoutVariable::typed(U256::from(9), 0, ValueType::Uint(8))];
+        let diff = a.diff_with_layout(&b, &typed).unwrap();
+        assert_eq!(diff.variables[0].name, "0x9");
+        assert_eq!(
+            (diff.variables[0].old.clone(), diff.variables[0].new.clone()),
+            (None, Some(DecodedValue::Uint(U256::from(5))))
+        );
+
+        assert_eq!(Snapshot::from_json(&b.to_json().unwrap()).unwrap(), b);
+        assert!(a.diff(&a).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Timing, counting and logging of the RPC calls made by [`Scroller`](crate::l1scroller::Scroller).
+//!
+//! Every call is logged through `tracing` inside the span of the `Scroller` method that
+//! made it. With the `metrics` feature the calls are also recorded through the `metrics`
+//! facade, to be exported by any recorder, e.g. [`install_prometheus`]:
+//!
+//! | metric                               | kind      | labels            |
+//! |--------------------------------------|-----------|-------------------|
+//! | `scroller_requests_total`            | counter   | `method`          |
+//! | `scroller_request_errors_total`      | counter   | `method`, `error` |
+//! | `scroller_request_duration_seconds`  | histogram | `method`          |
+//! | `scroller_slots_read_total`          | counter   | `method`          |
+//! | `scroller_cache_hits_total`          | counter   |                   |
+//! | `scroller_cache_misses_total`        | counter   |                   |
+//! | `scroller_fee_bump_retries_total`    | counter   |                   |
+//! | `scroller_reconnect_attempts_total`  | counter   | `outcome`         |
+//!
+//! `scroller_fee_bump_retries_total` counts the transactions resent with higher fees by
+//! [`send_with_fee_bump`](crate::gas::send_with_fee_bump), and
+//! `scroller_reconnect_attempts_total` the attempts of a pubsub transport to reconnect,
+//! by `outcome` (`connected` or `failed`).
+
+use std::future::Future;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+use web_time::Instant;
+
+use crate::error::L1ScrollerError;
+
+pub const REQUESTS_TOTAL: &str = "scroller_requests_total";
+pub const REQUEST_ERRORS_TOTAL: &str = "scroller_request_errors_total";
+pub const REQUEST_DURATION_SECONDS: &str = "scroller_request_duration_seconds";
+pub const SLOTS_READ_TOTAL: &str = "scroller_slots_read_total";
+pub const CACHE_HITS_TOTAL: &str = "scroller_cache_hits_total";
+pub const CACHE_MISSES_TOTAL: &str = "scroller_cache_misses_total";
+pub const FEE_BUMP_RETRIES_TOTAL: &str = "scroller_fee_bump_retries_total";
+pub const RECONNECT_ATTEMPTS_TOTAL: &str = "scroller_reconnect_attempts_total";
+
+/// Runs one RPC call made for `method`, reading `slots` slots.
+pub(crate) async fn observe<T>(
+    method: &'static str,
+    slots: usize,
+    call: impl Future<Output = Result<T, L1ScrollerError>>,
+) -> Result<T, L1ScrollerError> {
+    let start = Instant::now();
+    let result = call.await;
+    let elapsed = start.elapsed();
+    match &result {
+        Ok(_) => tracing::debug!(method, slots, ?elapsed, "rpc call"),
+        Err(err) => tracing::warn!(method, slots, ?elapsed, error = %err, "rpc call failed"),
+    }
+
+    #[cfg(feature = "metrics")]
+    {
+        metrics::counter!(REQUESTS_TOTAL, "method" => method).increment(1);
+        metrics::counter!(SLOTS_READ_TOTAL, "method" => method).increment(slots as u64);
+        metrics::histogram!(REQUEST_DURATION_SECONDS, "method" => method)
+            .record(elapsed.as_secs_f64());
+        if let Err(err) = &result {
+            metrics::counter!(REQUEST_ERRORS_TOTAL, "method" => method, "error" => err.class())
+                .increment(1);
+        }
+    }
+    result
+}
+
+/// Records the outcome of the cache lookups of one read.
+pub(crate) fn cache_lookup(hits: usize, misses: usize) {
+    tracing::debug!(hits, misses, "cache lookup");
+
+    #[cfg(feature = "metrics")]
+    {
+        metrics::counter!(CACHE_HITS_TOTAL).increment(hits as u64);
+        metrics::counter!(CACHE_MISSES_TOTAL).increment(misses as u64);
+    }
+}
+
+/// Records a transaction resent with bumped fees after it was rejected as underpriced.
+pub(crate) fn fee_bump_retry(bump: u32) {
+    tracing::debug!(bump, "resending with bumped fees");
+
+    #[cfg(feature = "metrics")]
+    metrics::counter!(FEE_BUMP_RETRIES_TOTAL).increment(1);
+}
+
+/// Records one attempt to re-establish a dropped pubsub connection.
+#[cfg(all(feature = "pubsub", not(target_arch = "wasm32")))]
+pub(crate) fn reconnect_attempt(connected: bool) {
+    #[cfg(feature = "metrics")]
+    {
+        let outcome = if connected { "connected" } else { "failed" };
+        metrics::counter!(RECONNECT_ATTEMPTS_TOTAL, "outcome" => outcome).increment(1);
+    }
+    #[cfg(not(feature = "metrics"))]
+    let _ = connected;
+}
+
+/// Installs a Prometheus recorder for the metrics above as the global `metrics` recorder.
+/// Render the returned handle to serve a scrape endpoint.
+#[cfg(feature = "metrics")]
+pub fn install_prometheus(
+) -> Result<metrics_exporter_prometheus::PrometheusHandle, metrics_exporter_prometheus::BuildError>
+{
+    use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(REQUEST_DURATION_SECONDS.to_string()),
+            &[
+                0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+            ],
+        )?
+        .install_recorder()
+}
+
+#[cfg(all(test, feature = "metrics"))]
+mod tests {
+    use metrics_exporter_prometheus::PrometheusBuilder;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_observe_records_errors() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        observe("l1sload", 2, async { Ok(()) }).await.unwrap();
+        observe("l1sload", 1, async {
+            Err::<(), _>(L1ScrollerError::PrecompileCallError)
+        })
+        .await
+        .unwrap_err();
+        cache_lookup(3, 1);
+        fee_bump_retry(1);
+        fee_bump_retry(2);
+
+        let output = handle.render();
+        assert!(output.contains(r#"scroller_requests_total{method="l1sload"} 2"#));
+        assert!(output.contains(r#"scroller_slots_read_total{method="l1sload"} 3"#));
+        assert!(output.contains(
+            r#"scroller_request_errors_total{method="l1sload",error="precompile_call"} 1"#
+        ));
+        assert!(output.contains("scroller_cache_hits_total 3"));
+        assert!(output.contains("scroller_fee_bump_retries_total 2"));
+    }
+}
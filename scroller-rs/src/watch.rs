@@ -0,0 +1,222 @@
+use std::{collections::VecDeque, time::Duration};
+
+use alloy::{
+    primitives::{Address, B256, U256},
+    rpc::types::BlockId,
+};
+use futures::{stream, Stream};
+
+#[cfg(feature = "pubsub")]
+use crate::pubsub::{fresh, Heads, MAX_HEAD_AGE};
+use crate::{
+    error::L1ScrollerError,
+    l1scroller::{L1ScrollerContracttype, Scroller},
+};
+
+/// Default delay between two polls of [`Scroller::watch`], about one Scroll block.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchConfig {
+    pub poll_interval: Duration,
+    /// Emit the first value of every slot as a change with `old: None`, instead of only
+    /// using it as the baseline.
+    pub emit_initial: bool,
+}
+
+impl Default for WatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            emit_initial: false,
+        }
+    }
+}
+
+/// A watched slot whose value changed between two polls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotChange {
+    pub slot: U256,
+    /// Previous value, `None` for the initial value when [`WatchConfig::emit_initial`] is set.
+    pub old: Option<B256>,
+    pub new: B256,
+    /// L1 block the new value was read at.
+    pub l1_block: u64,
+    /// L2 block the read was executed at.
+    pub l2_block: u64,
+}
+
+struct WatchState {
+    values: Vec<Option<B256>>,
+    l1_block: Option<u64>,
+    pending: VecDeque<SlotChange>,
+    polled: bool,
+    #[cfg(feature = "pubsub")]
+    heads: Option<Heads>,
+}
+
+impl Scroller<L1ScrollerContracttype> {
+    /// Watches `slots` of `l1_contract_addr` with the default [`WatchConfig`].
+    pub fn watch(
+        &self,
+        l1_contract_addr: Address,
+        slots: Vec<U256>,
+    ) -> impl Stream<Item = eyre::Result<SlotChange, L1ScrollerError>> + '_ {
+        self.watch_with(l1_contract_addr, slots, WatchConfig::default())
+    }
+
+    /// Polls `slots` every `config.poll_interval` and yields a [`SlotChange`] for each slot
+    /// whose value differs from the previous poll.
+    ///
+    /// Every poll reads the L1 block and the slots at the same L2 block. Slots are only
+    /// read again once the L1 block visible through L1Blocks has moved, since L1SLOAD
+    /// values cannot change in between. Errors are yielded without ending the stream.
+    ///
+    /// Over a pubsub transport, slots are polled on every new head instead, and every
+    /// `config.poll_interval` again while the latest head is stale (see
+    /// [`MAX_HEAD_AGE`](crate::pubsub::MAX_HEAD_AGE)) or once the `newHeads` subscription
+    /// ends.
+    pub fn watch_with(
+        &self,
+        l1_contract_addr: Address,
+        slots: Vec<U256>,
+        config: WatchConfig,
+    ) -> impl Stream<Item = eyre::Result<SlotChange, L1ScrollerError>> + '_ {
+        let state = WatchState {
+            values: vec![None; slots.len()],
+            l1_block: None,
+            pending: VecDeque::new(),
+            polled: false,
+            #[cfg(feature = "pubsub")]
+            heads: self.heads().map(|heads| {
+                let mut heads = heads.subscribe();
+                heads.borrow_and_update();
+                heads
+            }),
+        };
+        stream::unfold(state, move |mut state| {
+            let slots = slots.clone();
+            async move {
+                loop {
+                    if let Some(change) = state.pending.pop_front() {
+                        return Some((Ok(change), state));
+                    }
+                    if state.polled {
+                        state.next_poll(config.poll_interval).await;
+                    }
+                    state.polled = true;
+                    if let Err(err) = self
+                        .poll_slots(l1_contract_addr, &slots, &config, &mut state)
+                        .await
+                    {
+                        return Some((Err(err), state));
+                    }
+                }
+            }
+        })
+    }
+
+    async fn poll_slots(
+        &self,
+        l1_contract_addr: Address,
+        slots: &[U256],
+        config: &WatchConfig,
+        state: &mut WatchState,
+    ) -> eyre::Result<(), L1ScrollerError> {
+        let (l2_block, l1_block) = self.latest_blocks().await?;
+        let at = BlockId::number(l2_block);
+        if state.l1_block == Some(l1_block) {
+            return Ok(());
+        }
+        let words = self.read_words_at(l1_contract_addr, slots, at).await?;
+        let initial = state.l1_block.is_none();
+        state.l1_block = Some(l1_block);
+        state
+            .pending
+            .extend(diff(&mut state.values, slots, &words, l1_block, l2_block));
+        if initial && !config.emit_initial {
+            state.pending.clear();
+        }
+        Ok(())
+    }
+
+    /// Latest L2 block and the L1 block visible at it, from the latest head when following
+    /// `newHeads`.
+    async fn latest_blocks(&self) -> eyre::Result<(u64, u64), L1ScrollerError> {
+        #[cfg(feature = "pubsub")]
+        if let Some(head) = self.head() {
+            return Ok((head.l2_block, head.l1_block));
+        }
+        let l2_block = self.l2_block_number().await?;
+        let l1_block = self.l1_block_number_at(BlockId::number(l2_block)).await?;
+        Ok((l2_block, l1_block))
+    }
+}
+
+impl WatchState {
+    /// Waits for the next head, or `poll_interval` without a fresh one.
+    async fn next_poll(&mut self, poll_interval: Duration) {
+        #[cfg(feature = "pubsub")]
+        if let Some(heads) = &mut self.heads {
+            let wait = match fresh(*heads.borrow()) {
+                Some(_) => MAX_HEAD_AGE,
+                None => poll_interval,
+            };
+            match tokio::time::timeout(wait, heads.changed()).await {
+                Ok(Ok(())) | Err(_) => return,
+                Ok(Err(_)) => self.heads = None,
+            }
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+/// Records `words` into `values` and returns the slots that changed.
+fn diff(
+    values: &mut [Option<B256>],
+    slots: &[U256],
+    words: &[B256],
+    l1_block: u64,
+    l2_block: u64,
+) -> Vec<SlotChange> {
+    let mut changes = Vec::new();
+    for ((value, slot), word) in values.iter_mut().zip(slots).zip(words) {
+        if *value != Some(*word) {
+            changes.push(SlotChange {
+                slot: *slot,
+                old: *value,
+                new: *word,
+                l1_block,
+                l2_block,
+            });
+            *value = Some(*word);
+        }
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_deduplicates() {
+        let slots = [U256::from(0), U256::from(1)];
+        let mut values = vec![None; 2];
+        let first = [B256::with_last_byte(1), B256::with_last_byte(2)];
+        assert_eq!(diff(&mut values, &slots, &first, 10, 100).len(), 2);
+        assert!(diff(&mut values, &slots, &first, 11, 104).is_empty());
+
+        let second = [B256::with_last_byte(1), B256::with_last_byte(3)];
+        assert_eq!(
+            diff(&mut values, &slots, &second, 12, 108),
+            vec![SlotChange {
+                slot: U256::from(1),
+                old: Some(B256::with_last_byte(2)),
+                new: B256::with_last_byte(3),
+                l1_block: 12,
+                l2_block: 108,
+            }]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-const { assert } = require("ethers");
+const assert = require("node:assert");
 const createL1ScrollerSdk = require("./l1ScrollerSdk");
 
 // Initialize the SDK with your RPC URL
@@ -25,6 +25,7 @@ async function testSdk() {
         assert(cleanedString == "scroll");
     } catch (error) {
         console.error("Error:", error);
+        process.exitCode = 1;
     }
 }
 